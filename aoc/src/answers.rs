@@ -0,0 +1,62 @@
+// Expected answers, one `author day part answer` entry per line. Blank lines
+// and lines starting with `#` are ignored.
+
+use aoc_core::AocError;
+use input_read_util::ParseError;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+pub struct Answers {
+    entries: HashMap<(String, u8, u8), String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, AocError> {
+        let contents =
+            fs::read_to_string(path).map_err(|error| AocError::io(path.display(), error))?;
+        Self::parse(&contents).map_err(|error| {
+            // name the file, otherwise it reads like the puzzle input is malformed
+            AocError::MalformedInput(ParseError {
+                message: format!("{} in {}", error.message, path.display()),
+                ..error
+            })
+        })
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, ParseError> {
+        let mut entries = HashMap::new();
+
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [author, day, part, answer] = fields[..] else {
+                return Err(ParseError::new(
+                    index + 1,
+                    1,
+                    format!("expected `author day part answer`, got '{}'", line),
+                ));
+            };
+            let day = day
+                .parse::<u8>()
+                .map_err(|_| ParseError::new(index + 1, 1, format!("invalid day '{}'", day)))?;
+            let part = part
+                .parse::<u8>()
+                .map_err(|_| ParseError::new(index + 1, 1, format!("invalid part '{}'", part)))?;
+
+            entries.insert((author.to_string(), day, part), answer.to_string());
+        }
+
+        Ok(Answers { entries })
+    }
+
+    pub fn get(&self, author: &str, day: u8, part: u8) -> Option<&str> {
+        self.entries
+            .get(&(author.to_string(), day, part))
+            .map(String::as_str)
+    }
+}
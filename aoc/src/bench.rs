@@ -0,0 +1,81 @@
+// Times the parse step and each part of a solution separately over many
+// iterations so solutions (including different authors' takes on the same
+// day) can be compared phase by phase.
+
+use aoc_core::{AocError, Solution};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+pub type BenchFn = fn(&str, usize) -> Result<DayTimings, AocError>;
+
+pub struct DayTimings {
+    pub parse: PhaseStats,
+    pub part1: PhaseStats,
+    pub part2: PhaseStats,
+}
+
+pub struct PhaseStats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+}
+
+impl PhaseStats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+
+        let total: Duration = samples.iter().sum();
+        let mean = total / samples.len() as u32;
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        PhaseStats {
+            mean,
+            median,
+            min: samples[0],
+        }
+    }
+}
+
+pub fn bench<S: Solution>(input: &str, iterations: usize) -> Result<DayTimings, AocError> {
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut part1_samples = Vec::with_capacity(iterations);
+    let mut part2_samples = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = S::parse(black_box(input))?;
+        parse_samples.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(&parsed));
+        part1_samples.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(&parsed));
+        part2_samples.push(start.elapsed());
+    }
+
+    Ok(DayTimings {
+        parse: PhaseStats::from_samples(parse_samples),
+        part1: PhaseStats::from_samples(part1_samples),
+        part2: PhaseStats::from_samples(part2_samples),
+    })
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1_000.0)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1_000_000.0)
+    } else {
+        format!("{:.2}s", duration.as_secs_f64())
+    }
+}
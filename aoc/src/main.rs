@@ -0,0 +1,282 @@
+mod answers;
+mod bench;
+mod registry;
+mod report;
+
+use answers::Answers;
+use aoc_core::{exit_with, AocError};
+use input_read_util::{input_name, read_input_or_stdin};
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const USAGE: &str = "usage:
+    aoc list
+    aoc run [--author <name>] --day <n> [--part <n>] [--input <path>] [--format text|json]
+    aoc verify [--answers <path>]
+    aoc bench [--author <name>] [--day <n>] [--iterations <n>] [--format table|csv]";
+
+// registered inputs and the answers file are relative to the workspace root
+const WORKSPACE_ROOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+const DEFAULT_ANSWERS: &str = "answers.txt";
+const DEFAULT_BENCH_ITERATIONS: usize = 10;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.first().map(String::as_str) {
+        Some("list") => list(),
+        Some("run") => parse_flags(&args[1..]).and_then(|flags| run(&flags)),
+        Some("verify") => parse_flags(&args[1..]).and_then(|flags| verify(&flags)),
+        Some("bench") => parse_flags(&args[1..]).and_then(|flags| bench(&flags)),
+        _ => Err(AocError::Usage(USAGE.to_string())),
+    };
+
+    if let Err(error) = result {
+        exit_with(error);
+    }
+}
+
+fn list() -> Result<(), AocError> {
+    println!("{:<8} {:>4}", "author", "day");
+    for registration in registry::REGISTRY {
+        println!("{:<8} {:>4}", registration.author, registration.day);
+    }
+    Ok(())
+}
+
+fn run(flags: &HashMap<String, String>) -> Result<(), AocError> {
+    let day = numeric_flag(flags, "day")?;
+    // without --part both parts are run
+    let parts = match flags.get("part") {
+        Some(_) => vec![numeric_flag(flags, "part")?],
+        None => vec![1, 2],
+    };
+    // no --input (or `--input -`) reads the puzzle input from stdin
+    let input_path = flags.get("input").map(String::as_str);
+    let format = flags.get("format").map(String::as_str).unwrap_or("text");
+    if format != "text" && format != "json" {
+        return Err(AocError::Usage(format!(
+            "--format expects text or json, got '{}'",
+            format
+        )));
+    }
+
+    let registration = match flags.get("author") {
+        Some(author) => registry::find(author, day)?,
+        None => registry::find_only(day)?,
+    };
+
+    let input = read_input_or_stdin(input_path)
+        .map_err(|error| AocError::io(input_name(input_path), error))?;
+
+    let report = (registration.run)(&input, &parts)?;
+    for warning in &report.warnings {
+        eprintln!("warning: {}", warning);
+    }
+
+    if format == "json" {
+        println!("{}", report.to_json(registration.author, day));
+    } else if let [part] = &report.parts[..] {
+        println!("{}", part.answer);
+    } else {
+        for part in &report.parts {
+            println!("part {}: {}", part.part, part.answer);
+        }
+    }
+    Ok(())
+}
+
+fn verify(flags: &HashMap<String, String>) -> Result<(), AocError> {
+    let answers_path = match flags.get("answers") {
+        Some(path) => PathBuf::from(path),
+        None => Path::new(WORKSPACE_ROOT).join(DEFAULT_ANSWERS),
+    };
+    let answers = Answers::load(&answers_path)?;
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+
+    println!(
+        "{:<8} {:>4} {:>5}  {:<7} answer",
+        "author", "day", "part", "result"
+    );
+    for registration in registry::REGISTRY {
+        let input_path = Path::new(WORKSPACE_ROOT).join(registration.input);
+        let input = fs::read_to_string(&input_path);
+
+        for part in [1, 2] {
+            let expected = answers.get(registration.author, registration.day, part);
+            let outcome = match &input {
+                Ok(input) => (registration.solve)(input, part)
+                    .map(|answer| answer.to_string())
+                    .map_err(|error| error.to_string()),
+                Err(error) => Err(format!("unable to read {}: {}", registration.input, error)),
+            };
+
+            let (result, detail) = match (outcome, expected) {
+                (Err(error), _) => {
+                    failed += 1;
+                    ("error", error)
+                }
+                (Ok(answer), None) => {
+                    missing += 1;
+                    (
+                        "missing",
+                        format!("{} (no expected answer recorded)", answer),
+                    )
+                }
+                (Ok(answer), Some(expected)) if answer == expected => {
+                    passed += 1;
+                    ("pass", answer)
+                }
+                (Ok(answer), Some(expected)) => {
+                    failed += 1;
+                    ("FAIL", format!("{} (expected {})", answer, expected))
+                }
+            };
+
+            println!(
+                "{:<8} {:>4} {:>5}  {:<7} {}",
+                registration.author, registration.day, part, result, detail
+            );
+        }
+    }
+
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        passed, failed, missing
+    );
+
+    if failed > 0 {
+        return Err(AocError::VerificationFailed { failed });
+    }
+    Ok(())
+}
+
+fn bench(flags: &HashMap<String, String>) -> Result<(), AocError> {
+    let author = flags.get("author").map(String::as_str);
+    let day = match flags.get("day") {
+        Some(_) => Some(numeric_flag(flags, "day")?),
+        None => None,
+    };
+    let iterations = match flags.get("iterations") {
+        Some(value) => value
+            .parse::<usize>()
+            .ok()
+            .filter(|iterations| *iterations > 0)
+            .ok_or_else(|| {
+                AocError::Usage(format!(
+                    "--iterations expects a positive number, got '{}'",
+                    value
+                ))
+            })?,
+        None => DEFAULT_BENCH_ITERATIONS,
+    };
+    let format = flags.get("format").map(String::as_str).unwrap_or("table");
+    if format != "table" && format != "csv" {
+        return Err(AocError::Usage(format!(
+            "--format expects table or csv, got '{}'",
+            format
+        )));
+    }
+
+    // group the same day together so different authors' solutions sit side by side
+    let mut registrations: Vec<&registry::Registration> = registry::REGISTRY
+        .iter()
+        .filter(|r| author.is_none_or(|author| r.author == author))
+        .filter(|r| day.is_none_or(|day| r.day == day))
+        .collect();
+    registrations.sort_by_key(|r| (r.day, r.author));
+
+    if registrations.is_empty() {
+        return Err(AocError::Usage(
+            "no registered solutions match the given --author/--day".to_string(),
+        ));
+    }
+
+    if format == "csv" {
+        println!("author,day,phase,iterations,mean_ns,median_ns,min_ns");
+    } else {
+        println!(
+            "{:<8} {:>4} {:<6} {:>12} {:>12} {:>12}",
+            "author", "day", "phase", "mean", "median", "min"
+        );
+    }
+
+    for registration in registrations {
+        let input_path = Path::new(WORKSPACE_ROOT).join(registration.input);
+        let input = fs::read_to_string(&input_path)
+            .map_err(|error| AocError::io(registration.input, error))?;
+        let timings = (registration.bench)(&input, iterations)?;
+
+        for (phase, stats) in [
+            ("parse", &timings.parse),
+            ("part1", &timings.part1),
+            ("part2", &timings.part2),
+        ] {
+            if format == "csv" {
+                println!(
+                    "{},{},{},{},{},{},{}",
+                    registration.author,
+                    registration.day,
+                    phase,
+                    iterations,
+                    stats.mean.as_nanos(),
+                    stats.median.as_nanos(),
+                    stats.min.as_nanos()
+                );
+            } else {
+                println!(
+                    "{:<8} {:>4} {:<6} {:>12} {:>12} {:>12}",
+                    registration.author,
+                    registration.day,
+                    phase,
+                    bench::format_duration(stats.mean),
+                    bench::format_duration(stats.median),
+                    bench::format_duration(stats.min)
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// collects `--name value` pairs, rejecting anything that is not a flag followed by its value
+fn parse_flags(args: &[String]) -> Result<HashMap<String, String>, AocError> {
+    let mut flags = HashMap::new();
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        let Some(name) = arg.strip_prefix("--") else {
+            return Err(AocError::Usage(format!(
+                "unexpected argument '{}'\n{}",
+                arg, USAGE
+            )));
+        };
+        let Some(value) = args.next() else {
+            return Err(AocError::Usage(format!(
+                "missing value for --{}\n{}",
+                name, USAGE
+            )));
+        };
+        flags.insert(name.to_string(), value.clone());
+    }
+
+    Ok(flags)
+}
+
+fn required_flag<'a>(flags: &'a HashMap<String, String>, name: &str) -> Result<&'a str, AocError> {
+    flags
+        .get(name)
+        .map(String::as_str)
+        .ok_or_else(|| AocError::Usage(format!("missing required flag --{}\n{}", name, USAGE)))
+}
+
+fn numeric_flag(flags: &HashMap<String, String>, name: &str) -> Result<u8, AocError> {
+    let value = required_flag(flags, name)?;
+    value
+        .parse::<u8>()
+        .map_err(|_| AocError::Usage(format!("--{} expects a number, got '{}'", name, value)))
+}
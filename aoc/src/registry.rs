@@ -0,0 +1,66 @@
+// Every solution the runner knows how to dispatch to. Each day's crate
+// implements `aoc_core::Solution`, so a registration is just its author, day,
+// puzzle input (relative to the workspace root) and the monomorphised `solve`,
+// `run` and `bench` for that type.
+
+use crate::bench::{self, BenchFn};
+use crate::report::{self, RunFn};
+use aoc_core::{Answer, AocError, Solution};
+
+pub type SolveFn = fn(&str, u8) -> Result<Answer, AocError>;
+
+pub struct Registration {
+    pub author: &'static str,
+    pub day: u8,
+    pub input: &'static str,
+    pub solve: SolveFn,
+    pub run: RunFn,
+    pub bench: BenchFn,
+}
+
+const fn register<S: Solution>(author: &'static str, day: u8, input: &'static str) -> Registration {
+    Registration {
+        author,
+        day,
+        input,
+        solve: aoc_core::solve::<S>,
+        run: report::run::<S>,
+        bench: bench::bench::<S>,
+    }
+}
+
+pub const REGISTRY: &[Registration] = &[
+    register::<parker_day_1::Day1>("parker", 1, "parker/day_1/input/input.txt"),
+    register::<parker_day_2::Day2>("parker", 2, "parker/day_2/input/input.txt"),
+    register::<parker_day_3::Day3>("parker", 3, "parker/day_3/input/input.txt"),
+    register::<logan_day_5::Day5>("logan", 5, "logan/day-5/input.txt"),
+    register::<logan_day_6::Day6>("logan", 6, "logan/day-6/input.txt"),
+    register::<logan_day_9::Day9>("logan", 9, "logan/day-9/input.txt"),
+    register::<logan_day_11::Day11>("logan", 11, "logan/day-11/input.txt"),
+    register::<logan_day_12::Day12>("logan", 12, "logan/day-12/input.txt"),
+];
+
+pub fn find(author: &str, day: u8) -> Result<&'static Registration, AocError> {
+    REGISTRY
+        .iter()
+        .find(|r| r.author == author && r.day == day)
+        .ok_or_else(|| {
+            AocError::Usage(format!("no solution registered for {} day {}", author, day))
+        })
+}
+
+// without an author the day has to be unambiguous
+pub fn find_only(day: u8) -> Result<&'static Registration, AocError> {
+    let mut matching = REGISTRY.iter().filter(|r| r.day == day);
+    match (matching.next(), matching.next()) {
+        (Some(registration), None) => Ok(registration),
+        (None, _) => Err(AocError::Usage(format!(
+            "no solution registered for day {}",
+            day
+        ))),
+        (Some(_), Some(_)) => Err(AocError::Usage(format!(
+            "more than one author has solved day {}, pick one with --author",
+            day
+        ))),
+    }
+}
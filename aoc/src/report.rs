@@ -0,0 +1,74 @@
+// Runs the requested parts of a solution once, timing the parse step and each
+// part, so `aoc run` can report timings next to the answers.
+
+use aoc_core::{Answer, AocError, Json, Solution};
+use std::time::{Duration, Instant};
+
+pub type RunFn = fn(&str, &[u8]) -> Result<RunReport, AocError>;
+
+pub struct RunReport {
+    pub parse: Duration,
+    pub parts: Vec<PartReport>,
+    pub warnings: Vec<String>,
+}
+
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+pub fn run<S: Solution>(input: &str, parts: &[u8]) -> Result<RunReport, AocError> {
+    // check the parts up front so a bad one doesn't waste a parse
+    if let Some(part) = parts.iter().find(|part| !matches!(part, 1 | 2)) {
+        return Err(AocError::UnsupportedPart(*part));
+    }
+
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = if part == 1 {
+                S::part1(&parsed)
+            } else {
+                S::part2(&parsed)
+            };
+            PartReport {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect();
+
+    Ok(RunReport {
+        parse,
+        parts,
+        warnings: S::warnings(&parsed),
+    })
+}
+
+impl RunReport {
+    pub fn to_json(&self, author: &str, day: u8) -> Json {
+        let parts: Vec<Json> = self
+            .parts
+            .iter()
+            .map(|part| {
+                Json::object()
+                    .with("part", part.part)
+                    .with("answer", &part.answer)
+                    .with("time_ns", part.elapsed.as_nanos())
+            })
+            .collect();
+
+        Json::object()
+            .with("author", author)
+            .with("day", day)
+            .with("parts", parts)
+            .with("parse_time_ns", self.parse.as_nanos())
+    }
+}
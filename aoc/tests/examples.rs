@@ -0,0 +1,88 @@
+// Runs every solution against the sample inputs checked in next to each day,
+// using the expected answers from the puzzle statements.
+
+use aoc_core::{Answer, Solution};
+use std::fs;
+use std::path::Path;
+
+fn read_example(path: &str) -> String {
+    let full_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(path);
+    fs::read_to_string(&full_path)
+        .unwrap_or_else(|error| panic!("unable to read {}: {}", full_path.display(), error))
+}
+
+fn solve<S: Solution>(input: &str, part: u8) -> Answer {
+    aoc_core::solve::<S>(input, part).unwrap_or_else(|error| panic!("solve failed: {}", error))
+}
+
+macro_rules! example_tests {
+    ($($(#[$attr:meta])* $name:ident: $solution:ty, $path:expr, part $part:expr => $expected:expr;)*) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $name() {
+                let input = read_example($path);
+                assert_eq!(solve::<$solution>(&input, $part), Answer::from($expected as i64));
+            }
+        )*
+    };
+}
+
+example_tests! {
+    parker_day_1_part_1: parker_day_1::Day1, "parker/day_1/input/test", part 1 => 11;
+    parker_day_1_part_2: parker_day_1::Day1, "parker/day_1/input/test", part 2 => 31;
+
+    parker_day_2_part_1: parker_day_2::Day2, "parker/day_2/input/example", part 1 => 2;
+    parker_day_2_part_2: parker_day_2::Day2, "parker/day_2/input/example", part 2 => 4;
+    parker_day_2_part_1_late_direction_change: parker_day_2::Day2, "parker/day_2/input/test", part 1 => 0;
+    parker_day_2_part_2_late_direction_change: parker_day_2::Day2, "parker/day_2/input/test", part 2 => 0;
+
+    parker_day_3_part_1: parker_day_3::Day3, "parker/day_3/input/test", part 1 => 161;
+    parker_day_3_part_2: parker_day_3::Day3, "parker/day_3/input/test", part 2 => 48;
+
+    logan_day_5_part_1: logan_day_5::Day5, "logan/day-5/test.txt", part 1 => 143;
+    logan_day_5_part_2: logan_day_5::Day5, "logan/day-5/test.txt", part 2 => 123;
+
+    logan_day_6_part_1: logan_day_6::Day6, "logan/day-6/test.txt", part 1 => 41;
+    logan_day_6_part_2: logan_day_6::Day6, "logan/day-6/test.txt", part 2 => 6;
+
+    logan_day_11_part_1: logan_day_11::Day11, "logan/day-11/test.txt", part 1 => 55312;
+    logan_day_11_part_2: logan_day_11::Day11, "logan/day-11/test.txt", part 2 => 65601038650482_i64;
+
+    logan_day_12_part_1: logan_day_12::Day12, "logan/day-12/test.txt", part 1 => 1930;
+    logan_day_12_part_2: logan_day_12::Day12, "logan/day-12/test.txt", part 2 => 1206;
+}
+
+// day 9's sample file holds one disk map per line, each its own example
+fn day_9_examples() -> Vec<String> {
+    read_example("logan/day-9/test_input.txt")
+        .lines()
+        .map(String::from)
+        .collect()
+}
+
+#[test]
+fn logan_day_9_part_1() {
+    let expected = [1928, 60, 513];
+    for (disk_map, expected) in day_9_examples().iter().zip(expected) {
+        assert_eq!(
+            solve::<logan_day_9::Day9>(disk_map, 1),
+            Answer::from(expected),
+            "disk map {}",
+            disk_map
+        );
+    }
+}
+
+#[test]
+fn logan_day_9_part_2() {
+    let expected = [2858, 132, 513];
+    for (disk_map, expected) in day_9_examples().iter().zip(expected) {
+        assert_eq!(
+            solve::<logan_day_9::Day9>(disk_map, 2),
+            Answer::from(expected),
+            "disk map {}",
+            disk_map
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use input_read_util::{input_name, open_input_or_stdin, read_input_or_stdin, ParseError};
+use std::fmt;
+use std::io::{self, BufRead};
+use std::process;
+
+/// Everything that can stop a solution from producing an answer. Each kind
+/// exits with its own code so scripts can tell failures apart from answers
+/// (and from each other) without scraping stderr.
+#[derive(Debug)]
+pub enum AocError {
+    /// Bad command line arguments.
+    Usage(String),
+    /// The input (or another file) could not be read.
+    Io { path: String, source: io::Error },
+    /// The input was read but does not match the puzzle's format.
+    MalformedInput(ParseError),
+    /// The puzzle needs a start marker (such as day 6's `^`) that the input lacks.
+    MissingStart { marker: char },
+    /// Puzzles only have parts 1 and 2.
+    UnsupportedPart(u8),
+    /// `aoc verify` found answers that do not match the recorded ones.
+    VerificationFailed { failed: usize },
+}
+
+impl AocError {
+    pub fn io(path: impl fmt::Display, source: io::Error) -> Self {
+        AocError::Io {
+            path: path.to_string(),
+            source,
+        }
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            AocError::VerificationFailed { .. } => 1,
+            AocError::Usage(_) => 2,
+            AocError::Io { .. } => 3,
+            AocError::MalformedInput(_) => 4,
+            AocError::MissingStart { .. } => 5,
+            AocError::UnsupportedPart(_) => 6,
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Usage(message) => write!(f, "{}", message),
+            AocError::Io { path, source } => write!(f, "unable to read {}: {}", path, source),
+            AocError::MalformedInput(error) => write!(f, "malformed input at {}", error),
+            AocError::MissingStart { marker } => {
+                write!(f, "no starting position '{}' found in input", marker)
+            }
+            AocError::UnsupportedPart(part) => write!(
+                f,
+                "part {} does not exist, puzzles only have parts 1 and 2",
+                part
+            ),
+            AocError::VerificationFailed { failed } => {
+                write!(f, "{} solution(s) failed verification", failed)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            AocError::MalformedInput(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseError> for AocError {
+    fn from(error: ParseError) -> Self {
+        AocError::MalformedInput(error)
+    }
+}
+
+/// Reports `error` on stderr and exits with its exit code.
+pub fn exit_with(error: AocError) -> ! {
+    eprintln!("Error: {}", error);
+    process::exit(error.exit_code())
+}
+
+/// Reads the whole input from `path`, or from stdin when there is no path or it
+/// is `-`, exiting with an `AocError::Io` if it cannot be read.
+pub fn read_input_or_exit(path: Option<&str>) -> String {
+    read_input_or_stdin(path)
+        .unwrap_or_else(|error| exit_with(AocError::io(input_name(path), error)))
+}
+
+/// Like `read_input_or_exit`, but hands back a reader for inputs processed as they stream in.
+pub fn open_input_or_exit(path: Option<&str>) -> Box<dyn BufRead> {
+    open_input_or_stdin(path)
+        .unwrap_or_else(|error| exit_with(AocError::io(input_name(path), error)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_kind_of_error_has_its_own_exit_code() {
+        let errors = [
+            AocError::VerificationFailed { failed: 1 },
+            AocError::Usage(String::from("usage")),
+            AocError::io("input.txt", io::ErrorKind::NotFound.into()),
+            AocError::from(ParseError::new(1, 1, "bad")),
+            AocError::MissingStart { marker: '^' },
+            AocError::UnsupportedPart(3),
+        ];
+
+        let mut codes: Vec<i32> = errors.iter().map(AocError::exit_code).collect();
+        assert!(codes.iter().all(|code| *code != 0));
+        // dedup only drops adjacent repeats
+        codes.sort_unstable();
+        codes.dedup();
+        assert_eq!(codes.len(), errors.len());
+    }
+}
@@ -0,0 +1,175 @@
+use std::fmt;
+
+/// Just enough JSON to print the reports our tools emit, without pulling in a
+/// serialisation framework. Objects keep their keys in insertion order.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(i64),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn object() -> Self {
+        Json::Object(Vec::new())
+    }
+
+    /// Adds `key` to an object, for building one up field by field.
+    pub fn with(mut self, key: &str, value: impl Into<Json>) -> Self {
+        match &mut self {
+            Json::Object(fields) => fields.push((key.to_string(), value.into())),
+            _ => panic!("can only add field '{}' to a JSON object", key),
+        }
+        self
+    }
+}
+
+fn write_escaped(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    f.write_str("\"")?;
+    for char in value.chars() {
+        match char {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            char if char.is_control() => write!(f, "\\u{:04x}", char as u32)?,
+            char => write!(f, "{}", char)?,
+        }
+    }
+    f.write_str("\"")
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Json::Null => f.write_str("null"),
+            Json::Bool(value) => write!(f, "{}", value),
+            Json::Number(value) => write!(f, "{}", value),
+            Json::String(value) => write_escaped(f, value),
+            Json::Array(values) => {
+                f.write_str("[")?;
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(",")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                f.write_str("]")
+            }
+            Json::Object(fields) => {
+                f.write_str("{")?;
+                for (index, (key, value)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(",")?;
+                    }
+                    write_escaped(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                f.write_str("}")
+            }
+        }
+    }
+}
+
+macro_rules! impl_json_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Json {
+                fn from(value: $int) -> Self {
+                    Json::Number(value as i64)
+                }
+            }
+        )*
+    };
+}
+
+impl_json_from_int!(i32, i64, u8, u16, u32);
+
+// like `Answer`, wider integers stay numbers while they fit and are written as strings otherwise
+macro_rules! impl_json_from_wide_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Json {
+                fn from(value: $int) -> Self {
+                    match i64::try_from(value) {
+                        Ok(value) => Json::Number(value),
+                        Err(_) => Json::String(value.to_string()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_json_from_wide_int!(u64, usize, i128, u128);
+
+impl From<bool> for Json {
+    fn from(value: bool) -> Self {
+        Json::Bool(value)
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Json::String(value.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Json::String(value)
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Json::Null, Into::into)
+    }
+}
+
+impl<T: Into<Json>> From<Vec<T>> for Json {
+    fn from(values: Vec<T>) -> Self {
+        Json::Array(values.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_nested_values_in_order() {
+        let json = Json::object().with("author", "logan").with("day", 5).with(
+            "parts",
+            vec![Json::object().with("part", 1).with("answer", Json::Null)],
+        );
+
+        assert_eq!(
+            json.to_string(),
+            r#"{"author":"logan","day":5,"parts":[{"part":1,"answer":null}]}"#
+        );
+    }
+
+    #[test]
+    fn escapes_strings() {
+        let json = Json::from("say \"hi\"\n\\ \u{1}");
+        assert_eq!(json.to_string(), r#""say \"hi\"\n\\ \u0001""#);
+    }
+
+    #[test]
+    fn keeps_integers_too_wide_for_an_i64_exact() {
+        assert_eq!(
+            Json::from(u64::MAX).to_string(),
+            r#""18446744073709551615""#
+        );
+        assert_eq!(
+            Json::from(1u128 << 64).to_string(),
+            r#""18446744073709551616""#
+        );
+        assert_eq!(Json::from(42usize).to_string(), "42");
+    }
+}
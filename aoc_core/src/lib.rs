@@ -0,0 +1,107 @@
+mod error;
+mod json;
+// only built for tests, other crates opt in with the `testing` feature as a dev-dependency
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
+pub use error::{exit_with, open_input_or_exit, read_input_or_exit, AocError};
+pub use json::Json;
+use std::fmt;
+
+/// The answer to one part of a puzzle, kept as a value so it can be compared and
+/// consumed by tests and tooling instead of being printed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Answer::Number(value as i64)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_int!(i32, i64, u16, u32, usize);
+
+// wider answers stay numbers while they fit, and fall back to their exact digits otherwise
+macro_rules! impl_answer_from_wide_int {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    match i64::try_from(value) {
+                        Ok(value) => Answer::Number(value),
+                        Err(_) => Answer::Text(value.to_string()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_wide_int!(u64, i128, u128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<&Answer> for Json {
+    fn from(answer: &Answer) -> Self {
+        match answer {
+            Answer::Number(value) => Json::Number(*value),
+            Answer::Text(value) => Json::String(value.clone()),
+        }
+    }
+}
+
+/// A single day's puzzle: the raw input is parsed once into a typed model, and
+/// each part computes its answer from that model.
+pub trait Solution {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError>;
+
+    fn part1(input: &Self::Input) -> Answer;
+
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Anything about the parsed input worth telling the user, such as parts of it a
+    /// solution has to skip. Runners print these on stderr, away from the answers.
+    fn warnings(_input: &Self::Input) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// Parses `input` and solves the requested part of `S`.
+pub fn solve<S: Solution>(input: &str, part: u8) -> Result<Answer, AocError> {
+    let parsed = S::parse(input)?;
+    match part {
+        1 => Ok(S::part1(&parsed)),
+        2 => Ok(S::part2(&parsed)),
+        _ => Err(AocError::UnsupportedPart(part)),
+    }
+}
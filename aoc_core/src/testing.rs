@@ -0,0 +1,23 @@
+// Helpers shared by the tests of the puzzle crates.
+
+/// A small deterministic pseudo-random generator (Knuth's MMIX linear congruential
+/// generator) for tests that compare a solver against a brute force on many inputs.
+#[derive(Debug, Clone)]
+pub struct Lcg {
+    state: u64,
+}
+
+impl Lcg {
+    pub fn new(seed: u64) -> Self {
+        Lcg { state: seed }
+    }
+
+    /// The next value, in `0..bound`.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        self.state = self
+            .state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.state >> 33) % bound
+    }
+}
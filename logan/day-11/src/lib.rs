@@ -0,0 +1,146 @@
+use aoc_core::{Answer, AocError, Solution};
+use input_read_util::{parse_int_rows, Separator};
+use std::collections::HashMap;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        // stones are engraved with plain numbers, reject anything else up front and keep
+        // their digits as the solver expects them, so `+5` is 5 and `00` is 0
+        let rows = parse_int_rows::<u64>(input, Separator::Whitespace)?;
+        Ok(rows
+            .into_iter()
+            .flatten()
+            .map(|stone| stone.to_string())
+            .collect())
+    }
+
+    fn part1(stones: &Self::Input) -> Answer {
+        count_stones_after_blinks(stones, 25).into()
+    }
+
+    fn part2(stones: &Self::Input) -> Answer {
+        count_stones_after_blinks(stones, 75).into()
+    }
+}
+
+pub fn count_stones_after_blinks(parsed_stones: &[String], blinks: usize) -> i64 {
+    let mut result = 0;
+    let mut cache: HashMap<(String, usize), i64> = HashMap::new();
+
+    for stone in parsed_stones {
+        result += shift_stone_through_blinks(stone.clone(), blinks, &mut cache);
+    }
+
+    result
+}
+
+/*
+    Create a recursive algo for the stones
+*/
+pub fn shift_stone_through_blinks(
+    value: String,
+    remaining_blinks: usize,
+    cache: &mut HashMap<(String, usize), i64>,
+) -> i64 {
+    let mut result: i64 = 0;
+    // base case
+    if remaining_blinks == 0 {
+        result += 1
+    } else {
+        let value_magnitude = value.len();
+        let next_itr_blinks = remaining_blinks - 1;
+
+        // if there is a value in the cache with same number and amount of blinks, return that to save calculation time
+        // else determine value
+        match cache.get(&(value.clone(), remaining_blinks)) {
+            // if this number has been
+            Some(cached_result) => return *cached_result,
+            None => {
+                if value == "0" {
+                    let new_value = String::from("1");
+                    result += shift_stone_through_blinks(new_value, next_itr_blinks, cache);
+                } else if value_magnitude.is_multiple_of(2) {
+                    let mut first_half = value.clone();
+                    let second_half =
+                        trim_leading_zeros(&first_half.split_off(value_magnitude / 2));
+
+                    result += shift_stone_through_blinks(first_half, next_itr_blinks, cache);
+                    result += shift_stone_through_blinks(second_half, next_itr_blinks, cache);
+                } else {
+                    let new_value = multiply_by_2024(&value);
+                    result += shift_stone_through_blinks(new_value, next_itr_blinks, cache);
+                }
+                cache.insert((value, remaining_blinks), result);
+            }
+        }
+    }
+
+    result
+}
+
+// "0012" is engraved as 12, with a lone 0 left for a stone that is all zeros
+fn trim_leading_zeros(value: &str) -> String {
+    match value.trim_start_matches('0') {
+        "" => String::from("0"),
+        trimmed => trimmed.to_string(),
+    }
+}
+
+/*
+    long multiplication on the decimal digits, so stones never outgrow an integer type
+    - `value` is a number without leading zeros, as `Day11::parse` and the split leave them
+    - walk the digits from least to most significant, carrying into the next one
+*/
+fn multiply_by_2024(value: &str) -> String {
+    let mut digits: Vec<u8> = Vec::with_capacity(value.len() + 4);
+    let mut carry: u32 = 0;
+    for digit in value.bytes().rev() {
+        let product = u32::from(digit - b'0') * 2024 + carry;
+        digits.push(b'0' + (product % 10) as u8);
+        carry = product / 10;
+    }
+    while carry > 0 {
+        digits.push(b'0' + (carry % 10) as u8);
+        carry /= 10;
+    }
+    digits.reverse();
+
+    String::from_utf8(digits).expect("only ascii digits were pushed")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn multiplies_stones_past_the_range_of_an_i64() {
+        assert_eq!(multiply_by_2024("1"), "2024");
+        assert_eq!(
+            multiply_by_2024("12345678901234567"),
+            "24987654096098763608"
+        );
+    }
+
+    #[test]
+    fn blinks_at_stones_that_overflowed_an_i64() {
+        let stones = Day11::parse("12345678901234567").unwrap();
+
+        assert_eq!(count_stones_after_blinks(&stones, 1), 1);
+        // 24987654096098763608 splits into 2498765409 and 6098763608
+        assert_eq!(count_stones_after_blinks(&stones, 2), 2);
+        assert!(count_stones_after_blinks(&stones, 75) > 0);
+    }
+
+    #[test]
+    fn engraves_stones_as_plain_numbers() {
+        assert_eq!(Day11::parse("00 +5 017").unwrap(), vec!["0", "5", "17"]);
+
+        let blink = |input: &str| count_stones_after_blinks(&Day11::parse(input).unwrap(), 75);
+        assert_eq!(blink("00"), blink("0"));
+        assert_eq!(blink("+5"), blink("5"));
+    }
+}
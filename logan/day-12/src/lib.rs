@@ -0,0 +1,22 @@
+use aoc_core::{Answer, AocError, Solution};
+
+pub mod question1;
+pub mod question2;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = question1::AreaMap;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(question1::parse_farm(input)?)
+    }
+
+    fn part1(map: &Self::Input) -> Answer {
+        question1::calculate_total_cost(map).into()
+    }
+
+    fn part2(map: &Self::Input) -> Answer {
+        question2::calculate_total_cost(map).into()
+    }
+}
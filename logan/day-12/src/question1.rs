@@ -0,0 +1,74 @@
+use aoc_grid::{Grid, Point};
+use input_read_util::ParseError;
+
+// representation of entire input, one plant type per plot
+pub type AreaMap = Grid<char>;
+pub type Region = Vec<Point>;
+
+pub fn parse_farm(input: &str) -> Result<AreaMap, ParseError> {
+    Grid::parse(input)
+}
+
+/*
+   the fence between two plots is only needed when their plant types differ,
+   so each plot contributes 4 minus the number of matching neighbours
+*/
+fn clashing_perimeter(area_map: &AreaMap, plot: Point) -> usize {
+    let matching_neighbours = area_map
+        .neighbours4(plot)
+        .filter(|neighbour| area_map[*neighbour] == area_map[plot])
+        .count();
+
+    4 - matching_neighbours
+}
+
+pub fn calculate_total_cost(map: &AreaMap) -> i64 {
+    create_regions(map)
+        .iter()
+        .map(|region| {
+            let area = region.len() as i64;
+            let perim_len: i64 = region
+                .iter()
+                .map(|plot| clashing_perimeter(map, *plot) as i64)
+                .sum();
+            area * perim_len
+        })
+        .sum()
+}
+
+pub fn create_regions(area_map: &AreaMap) -> Vec<Region> {
+    let mut regions = Vec::new();
+    let mut registered: Grid<bool> = area_map.map(|_| false);
+
+    for plot in area_map.points() {
+        if !registered[plot] {
+            regions.push(create_region_from_plot(area_map, plot, &mut registered));
+        }
+    }
+
+    regions
+}
+
+// expands from a single plot to grab all connected plots of the same type
+fn create_region_from_plot(
+    area_map: &AreaMap,
+    start: Point,
+    registered: &mut Grid<bool>,
+) -> Region {
+    let mut region = Vec::new();
+    let mut to_visit = vec![start];
+    registered[start] = true;
+
+    while let Some(plot) = to_visit.pop() {
+        region.push(plot);
+
+        for neighbour in area_map.neighbours4(plot) {
+            if !registered[neighbour] && area_map[neighbour] == area_map[start] {
+                registered[neighbour] = true;
+                to_visit.push(neighbour);
+            }
+        }
+    }
+
+    region
+}
@@ -0,0 +1,45 @@
+use crate::question1::{create_regions, AreaMap, Region};
+use aoc_grid::{Direction, Point};
+
+/*
+   A region has as many sides as it has corners, so count corners instead of walking fences.
+   For each plot, look at every pair of adjacent directions (e.g. north and east):
+   - outer corner: neither neighbour is in the region
+   - inner corner: both neighbours are in the region but the diagonal between them is not
+*/
+fn count_corners(area_map: &AreaMap, plot: Point) -> usize {
+    let plant_type = area_map[plot];
+    let is_same_region = |neighbour: Option<Point>| {
+        neighbour.is_some_and(|neighbour| area_map[neighbour] == plant_type)
+    };
+
+    Direction::ALL
+        .into_iter()
+        .filter(|direction| {
+            let clockwise = direction.turn_right();
+            let first = area_map.step(plot, *direction);
+            let second = area_map.step(plot, clockwise);
+            let diagonal = first.and_then(|first| area_map.step(first, clockwise));
+
+            match (is_same_region(first), is_same_region(second)) {
+                (false, false) => true,
+                (true, true) => !is_same_region(diagonal),
+                _ => false,
+            }
+        })
+        .count()
+}
+
+fn count_sides(area_map: &AreaMap, region: &Region) -> usize {
+    region
+        .iter()
+        .map(|plot| count_corners(area_map, *plot))
+        .sum()
+}
+
+pub fn calculate_total_cost(map: &AreaMap) -> i64 {
+    create_regions(map)
+        .iter()
+        .map(|region| region.len() as i64 * count_sides(map, region) as i64)
+        .sum()
+}
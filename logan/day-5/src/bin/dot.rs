@@ -0,0 +1,39 @@
+use aoc_core::{exit_with, read_input_or_exit, AocError};
+use logan_day_5::dot::to_dot;
+use logan_day_5::PrintQueue;
+use std::env;
+
+const USAGE: &str = "dot [<path_to_input> | -] [<update_number>]";
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() > 3 {
+        exit_with(AocError::Usage(format!(
+            "Invalid number of args\n{}",
+            USAGE
+        )));
+    }
+
+    let file_path = args.get(1).map(String::as_str);
+    let input = read_input_or_exit(file_path);
+
+    let queue = match PrintQueue::parse(&input) {
+        Ok(queue) => queue,
+        Err(error) => exit_with(error.into()),
+    };
+
+    // updates are numbered from 1 in the order they appear
+    let update = args.get(2).map(|number| match number.parse::<usize>() {
+        Ok(number) if (1..=queue.instructions.len()).contains(&number) => {
+            queue.instructions[number - 1].as_slice()
+        }
+        _ => exit_with(AocError::Usage(format!(
+            "update_number must be between 1 and {}, got '{}'\n{}",
+            queue.instructions.len(),
+            number,
+            USAGE
+        ))),
+    });
+
+    print!("{}", to_dot(&queue.rule_set, update));
+}
@@ -0,0 +1,50 @@
+use aoc_core::{exit_with, read_input_or_exit, AocError};
+use logan_day_5::PrintQueue;
+use std::env;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() > 2 {
+        exit_with(AocError::Usage(String::from(
+            "Invalid number of args\nvalidate [<path_to_input> | -]",
+        )));
+    }
+
+    let file_path = args.get(1).map(String::as_str);
+    let input = read_input_or_exit(file_path);
+
+    let queue = match PrintQueue::parse(&input) {
+        Ok(queue) => queue,
+        Err(error) => exit_with(error.into()),
+    };
+
+    // a cycle in the rules as a whole is fine as long as no update holds all of its pages
+    match queue.rule_set.find_cycle() {
+        Some(cycle) => println!("rules: contain a cycle, e.g. {}", cycle),
+        None => println!("rules: no cycles"),
+    }
+
+    let mut contradictory = 0;
+    for (index, (instruction, line)) in queue
+        .instructions
+        .iter()
+        .zip(&queue.update_lines)
+        .enumerate()
+    {
+        if let Some(cycle) = queue.rule_set.find_cycle_within(instruction) {
+            contradictory += 1;
+            println!(
+                "update {} (line {}): contradictory, cycle {}",
+                index + 1,
+                line,
+                cycle
+            );
+        }
+    }
+
+    println!(
+        "{} of {} updates have contradictory rules",
+        contradictory,
+        queue.instructions.len()
+    );
+}
@@ -0,0 +1,70 @@
+use aoc_core::{exit_with, read_input_or_exit, AocError, Json};
+use logan_day_5::violations::violation_report;
+use logan_day_5::PrintQueue;
+use std::env;
+
+const USAGE: &str = "violations [--format text|json] [<path_to_input> | -]";
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+
+    let mut format = String::from("text");
+    if let Some(index) = args.iter().position(|arg| arg == "--format") {
+        match args.get(index + 1).map(String::as_str) {
+            Some(value @ ("text" | "json")) => format = value.to_string(),
+            _ => exit_with(AocError::Usage(format!(
+                "--format expects text or json\n{}",
+                USAGE
+            ))),
+        }
+        args.drain(index..index + 2);
+    }
+    if args.len() > 1 {
+        exit_with(AocError::Usage(format!(
+            "Invalid number of args\n{}",
+            USAGE
+        )));
+    }
+
+    let file_path = args.first().map(String::as_str);
+    let input = read_input_or_exit(file_path);
+
+    let queue = match PrintQueue::parse(&input) {
+        Ok(queue) => queue,
+        Err(error) => exit_with(error.into()),
+    };
+
+    // only updates that break a rule or hold pages without any rules are listed
+    let reports: Vec<_> = queue
+        .instructions
+        .iter()
+        .zip(&queue.update_lines)
+        .enumerate()
+        .map(|(index, (instruction, line))| {
+            (
+                index + 1,
+                *line,
+                instruction,
+                violation_report(&queue.rule_set, instruction),
+            )
+        })
+        .filter(|(_, _, _, report)| !report.is_empty())
+        .collect();
+
+    if format == "json" {
+        let updates: Vec<Json> = reports
+            .iter()
+            .map(|(number, line, instruction, report)| report.to_json(*number, *line, instruction))
+            .collect();
+        println!("{}", Json::object().with("updates", updates));
+    } else {
+        for (number, line, instruction, report) in &reports {
+            println!("{}", report.to_text(*number, *line, instruction));
+        }
+        println!(
+            "{} of {} updates break rules or have unruled pages",
+            reports.len(),
+            queue.instructions.len()
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use crate::question2::{PageNumber, RuleSet};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+const SATISFIED_COLOR: &str = "darkgreen";
+const VIOLATED_COLOR: &str = "red";
+
+// Renders the rules as a Graphviz digraph, e.g. `dot -Tsvg rules.dot -o rules.svg`.
+// Given an update, only its pages and the rules between them are drawn: each
+// page is labelled with its position in the update, and each rule is coloured
+// by whether the update as printed keeps it.
+pub fn to_dot(rule_set: &RuleSet, update: Option<&[PageNumber]>) -> String {
+    let mut dot = String::from("digraph rules {\n    rankdir=LR;\n    node [shape=circle];\n");
+
+    let Some(update) = update else {
+        let mut rules = rule_set.rules();
+        rules.sort_unstable();
+        for (first, second) in rules {
+            writeln!(dot, "    {} -> {};", first, second).unwrap();
+        }
+        dot.push_str("}\n");
+        return dot;
+    };
+
+    let positions: HashMap<PageNumber, usize> = update
+        .iter()
+        .enumerate()
+        .map(|(index, &page)| (page, index))
+        .collect();
+
+    for (index, page) in update.iter().enumerate() {
+        writeln!(dot, "    {} [label=\"{}\\n#{}\"];", page, page, index + 1).unwrap();
+    }
+    for (first, second) in rule_set.rules_within(update) {
+        let color = if positions[&first] < positions[&second] {
+            SATISFIED_COLOR
+        } else {
+            VIOLATED_COLOR
+        };
+        writeln!(dot, "    {} -> {} [color={}];", first, second, color).unwrap();
+    }
+    dot.push_str("}\n");
+
+    dot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colours_the_rules_an_update_breaks() {
+        let mut rule_set = RuleSet::new();
+        rule_set.add_rule(97, 75);
+        rule_set.add_rule(75, 13);
+        rule_set.add_rule(47, 61);
+
+        assert_eq!(
+            to_dot(&rule_set, Some(&[75, 97, 13])),
+            "digraph rules {
+    rankdir=LR;
+    node [shape=circle];
+    75 [label=\"75\\n#1\"];
+    97 [label=\"97\\n#2\"];
+    13 [label=\"13\\n#3\"];
+    75 -> 13 [color=darkgreen];
+    97 -> 75 [color=red];
+}
+"
+        );
+        assert!(to_dot(&rule_set, None).contains("    47 -> 61;\n"));
+    }
+}
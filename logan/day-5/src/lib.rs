@@ -0,0 +1,156 @@
+use aoc_core::{Answer, AocError, Solution};
+use input_read_util::{parse_int_rows, split_sections, ParseError, Separator};
+use question2::{PageNumber, PrintInstruction, RuleSet};
+use std::collections::HashSet;
+
+pub mod dot;
+pub mod minimal_repair;
+pub mod question2;
+pub mod rule_set;
+pub mod violations;
+
+pub struct Day5;
+
+pub struct PrintQueue {
+    pub rule_set: RuleSet,
+    pub instructions: Vec<PrintInstruction>,
+    // the input line each update was read from
+    pub update_lines: Vec<usize>,
+}
+
+// summed as u64, as the middle pages of a few updates can add up past a u16
+fn sum_of_middle_pages(instructions: Vec<PrintInstruction>) -> u64 {
+    instructions
+        .iter()
+        .map(|instruction| u64::from(instruction[instruction.len() / 2]))
+        .sum()
+}
+
+fn repeated_page(instruction: &[PageNumber]) -> Option<PageNumber> {
+    let mut seen = HashSet::new();
+    instruction.iter().copied().find(|page| !seen.insert(*page))
+}
+
+impl PrintQueue {
+    // parses the rules and updates without checking whether the rules contradict each other
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let sections = split_sections(input);
+        let (rules_section, instructions_section) = match sections[..] {
+            [rules, instructions] => (rules, instructions),
+            [_, _, extra, ..] => {
+                return Err(ParseError::new(
+                    extra.first_line,
+                    1,
+                    "unexpected section after the updates",
+                ));
+            }
+            _ => {
+                return Err(ParseError::new(
+                    input.lines().count() + 1,
+                    1,
+                    "expected a rules section and an updates section separated by a blank line",
+                ));
+            }
+        };
+
+        let mut rule_set = RuleSet::new();
+        let rules = parse_int_rows::<PageNumber>(rules_section.text, Separator::Char('|'))
+            .map_err(|error| rules_section.locate(error))?;
+        for (index, rule) in rules.iter().enumerate() {
+            let [first, second] = rule[..] else {
+                let error = ParseError::new(index + 1, 1, "expected a rule of the form X|Y");
+                return Err(rules_section.locate(error));
+            };
+            rule_set.add_rule(first, second);
+        }
+
+        let instructions =
+            parse_int_rows::<PageNumber>(instructions_section.text, Separator::Char(','))
+                .map_err(|error| instructions_section.locate(error))?;
+        for (index, instruction) in instructions.iter().enumerate() {
+            if let Some(page) = repeated_page(instruction) {
+                let error = ParseError::new(
+                    index + 1,
+                    1,
+                    format!("page {} appears more than once in this update", page),
+                );
+                return Err(instructions_section.locate(error));
+            }
+        }
+
+        // sections hold no blank lines, so the updates are on consecutive lines
+        let update_lines = (0..instructions.len())
+            .map(|index| instructions_section.first_line + index)
+            .collect();
+
+        Ok(PrintQueue {
+            rule_set,
+            instructions,
+            update_lines,
+        })
+    }
+}
+
+impl Solution for Day5 {
+    type Input = PrintQueue;
+
+    // updates whose rules contradict each other are kept: part 1 counts them as invalid
+    // and part 2 skips them, as no order of their pages keeps every rule
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(PrintQueue::parse(input)?)
+    }
+
+    fn part1(queue: &Self::Input) -> Answer {
+        let (valid_instructions, _) =
+            question2::categorize_instructions(&queue.rule_set, queue.instructions.clone());
+        sum_of_middle_pages(valid_instructions).into()
+    }
+
+    fn part2(queue: &Self::Input) -> Answer {
+        let (_, invalid_instructions) =
+            question2::categorize_instructions(&queue.rule_set, queue.instructions.clone());
+        let (repaired_instructions, _contradictions) =
+            question2::repair_invalid_instructions(&queue.rule_set, invalid_instructions);
+        sum_of_middle_pages(repaired_instructions).into()
+    }
+
+    fn warnings(queue: &Self::Input) -> Vec<String> {
+        let (_, invalid_instructions) =
+            question2::categorize_instructions(&queue.rule_set, queue.instructions.clone());
+        let (_, contradictions) =
+            question2::repair_invalid_instructions(&queue.rule_set, invalid_instructions);
+        contradictions
+            .iter()
+            .map(|contradiction| format!("{}, part 2 leaves it out", contradiction))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_contradictory_updates_as_invalid_without_repairing_them() {
+        let input = "1|2\n2|3\n3|1\n4|5\n5|6\n\n1,2,3\n4,5,6\n6,5,4\n";
+        let queue = Day5::parse(input).unwrap();
+
+        assert_eq!(Day5::part1(&queue), Answer::Number(5));
+        assert_eq!(Day5::part2(&queue), Answer::Number(5));
+
+        let (_, _, contradictions) =
+            question2::evaluate_instructions(&queue.rule_set, queue.instructions.clone());
+        assert_eq!(contradictions.len(), 1);
+        assert_eq!(contradictions[0].instruction, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn sums_middle_pages_past_the_range_of_a_u16() {
+        let input =
+            "40000|40001\n40001|40002\n\n40000,40001,40002\n40002,40001,40000\n40002,40001,40000\n";
+        let queue = Day5::parse(input).unwrap();
+
+        assert_eq!(Day5::part1(&queue), Answer::Number(40001));
+        assert_eq!(Day5::part2(&queue), Answer::Number(80002));
+    }
+}
@@ -0,0 +1,336 @@
+use crate::question2::{Contradiction, PageNumber, PrintInstruction, RuleSet};
+use std::collections::HashMap;
+
+// a page taken out of an update and put back at another position, both 0-based
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PageMove {
+    pub page: PageNumber,
+    pub from: usize,
+    pub to: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinimalRepair {
+    pub pages: PrintInstruction,
+    // the pages taken out and put back at another index, in the order they were printed
+    // originally. A page put back at its own index is left out, even though the pages
+    // around it may have changed.
+    pub moves: Vec<PageMove>,
+}
+
+// Repairs an update by moving as few pages as possible. The pages that stay put
+// must not contradict each other: no page may have to come before one printed
+// earlier, directly or through a chain of rules between the update's pages.
+// Contradicting pairs form a partial order on the update's positions, so the
+// largest set of pages to keep is a maximum antichain of it, found through a
+// maximum bipartite matching. The rest are then slotted in around them by a
+// topological sort with the kept pages chained together in their printed order.
+pub fn minimal_repair(
+    rule_set: &RuleSet,
+    instruction: &[PageNumber],
+) -> Result<MinimalRepair, Contradiction> {
+    let contradiction = |cycle| Contradiction {
+        instruction: instruction.to_vec(),
+        cycle,
+    };
+    if let Some(cycle) = rule_set.find_cycle_within(instruction) {
+        return Err(contradiction(cycle));
+    }
+
+    let must_precede = reachability(rule_set, instruction);
+    let len = instruction.len();
+    // `later[i]` are the positions after `i` holding a page that has to come before it
+    let later: Vec<Vec<usize>> = (0..len)
+        .map(|i| (i + 1..len).filter(|&j| must_precede[j][i]).collect())
+        .collect();
+    let kept = maximum_antichain(&later);
+
+    let mut constraints = RuleSet::new();
+    for (first, second) in rule_set.rules_within(instruction) {
+        constraints.add_rule(first, second);
+    }
+    for pair in kept.windows(2) {
+        constraints.add_rule(instruction[pair[0]], instruction[pair[1]]);
+    }
+    // the kept pages never contradict the rules, so chaining them cannot close a cycle
+    let ordering = constraints
+        .topological_order(instruction)
+        .map_err(contradiction)?;
+
+    let new_positions: HashMap<PageNumber, usize> = ordering
+        .pages
+        .iter()
+        .enumerate()
+        .map(|(index, &page)| (page, index))
+        .collect();
+    let moves = (0..len)
+        .filter(|index| !kept.contains(index))
+        .map(|index| PageMove {
+            page: instruction[index],
+            from: index,
+            to: new_positions[&instruction[index]],
+        })
+        .filter(|page_move| page_move.from != page_move.to)
+        .collect();
+
+    Ok(MinimalRepair {
+        pages: ordering.pages,
+        moves,
+    })
+}
+
+// `must_precede[a][b]` when the rules between the update's pages force position `a` before `b`
+fn reachability(rule_set: &RuleSet, instruction: &[PageNumber]) -> Vec<Vec<bool>> {
+    let positions: HashMap<PageNumber, usize> = instruction
+        .iter()
+        .enumerate()
+        .map(|(index, &page)| (page, index))
+        .collect();
+    let len = instruction.len();
+    let mut must_precede = vec![vec![false; len]; len];
+
+    for (start, reached) in must_precede.iter_mut().enumerate() {
+        let mut stack = vec![start];
+        while let Some(current) = stack.pop() {
+            for next in rule_set.successors(instruction[current]) {
+                if let Some(&position) = positions.get(next) {
+                    if !reached[position] {
+                        reached[position] = true;
+                        stack.push(position);
+                    }
+                }
+            }
+        }
+    }
+
+    must_precede
+}
+
+// The largest set of positions with no `later` relation between any two, in
+// increasing order. By Kőnig's theorem the positions reachable from unmatched
+// left vertices along alternating paths give a minimum vertex cover, and the
+// positions whose left copy is reachable but whose right copy is not are exactly
+// a maximum antichain.
+fn maximum_antichain(later: &[Vec<usize>]) -> Vec<usize> {
+    let len = later.len();
+    let mut matched_left: Vec<Option<usize>> = vec![None; len];
+    let mut matched_right: Vec<Option<usize>> = vec![None; len];
+
+    for left in 0..len {
+        let mut visited = vec![false; len];
+        augment(
+            left,
+            later,
+            &mut visited,
+            &mut matched_left,
+            &mut matched_right,
+        );
+    }
+
+    let mut reached_left = vec![false; len];
+    let mut reached_right = vec![false; len];
+    let mut stack: Vec<usize> = (0..len)
+        .filter(|&left| matched_left[left].is_none())
+        .collect();
+    for &left in &stack {
+        reached_left[left] = true;
+    }
+    while let Some(left) = stack.pop() {
+        for &right in &later[left] {
+            if reached_right[right] || matched_left[left] == Some(right) {
+                continue;
+            }
+            reached_right[right] = true;
+            if let Some(next) = matched_right[right] {
+                if !reached_left[next] {
+                    reached_left[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+    }
+
+    (0..len)
+        .filter(|&index| reached_left[index] && !reached_right[index])
+        .collect()
+}
+
+// Kuhn's augmenting path search from `left`
+fn augment(
+    left: usize,
+    later: &[Vec<usize>],
+    visited: &mut [bool],
+    matched_left: &mut [Option<usize>],
+    matched_right: &mut [Option<usize>],
+) -> bool {
+    for &right in &later[left] {
+        if visited[right] {
+            continue;
+        }
+        visited[right] = true;
+        let is_free = match matched_right[right] {
+            None => true,
+            Some(other) => augment(other, later, visited, matched_left, matched_right),
+        };
+        if is_free {
+            matched_left[left] = Some(right);
+            matched_right[right] = Some(left);
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::testing::Lcg;
+
+    const EXAMPLE_RULES: &str = "47|53 97|13 97|61 97|47 75|29 61|13 75|53 29|13 97|29 53|29 61|53 97|53 61|29 47|13 75|47 97|75 47|61 75|61 47|29 75|13 53|13";
+
+    fn rule_set(rules: &str) -> RuleSet {
+        let mut rule_set = RuleSet::new();
+        for rule in rules.split_whitespace() {
+            let (first, second) = rule.split_once('|').unwrap();
+            rule_set.add_rule(first.parse().unwrap(), second.parse().unwrap());
+        }
+        rule_set
+    }
+
+    // the pages that are not part of the longest run printed in the same order before and after
+    fn pages_out_of_order(instruction: &[PageNumber], repaired: &[PageNumber]) -> usize {
+        let mut longest = vec![vec![0; repaired.len() + 1]; instruction.len() + 1];
+        for (i, page) in instruction.iter().enumerate() {
+            for (j, repaired_page) in repaired.iter().enumerate() {
+                longest[i + 1][j + 1] = if page == repaired_page {
+                    longest[i][j] + 1
+                } else {
+                    longest[i][j + 1].max(longest[i + 1][j])
+                };
+            }
+        }
+        instruction.len() - longest[instruction.len()][repaired.len()]
+    }
+
+    // tries every subset of pages to keep, largest first
+    fn fewest_moves_by_brute_force(rule_set: &RuleSet, instruction: &[PageNumber]) -> usize {
+        let must_precede = reachability(rule_set, instruction);
+        let len = instruction.len();
+        (0u32..1 << len)
+            .filter(|subset| {
+                (0..len).all(|i| {
+                    (i + 1..len).all(|j| {
+                        subset & (1 << i) == 0 || subset & (1 << j) == 0 || !must_precede[j][i]
+                    })
+                })
+            })
+            .map(|subset| len - subset.count_ones() as usize)
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn moves_as_few_pages_as_possible_in_the_example() {
+        let rules = rule_set(EXAMPLE_RULES);
+
+        for (instruction, moved) in [
+            (vec![75, 97, 47, 61, 53], 1),
+            (vec![61, 13, 29], 1),
+            (vec![97, 13, 75, 29, 47], 2),
+        ] {
+            let repair = minimal_repair(&rules, &instruction).unwrap();
+            assert!(rules.is_ordered(&repair.pages), "{:?}", repair);
+            assert_eq!(
+                pages_out_of_order(&instruction, &repair.pages),
+                moved,
+                "{:?}",
+                instruction
+            );
+        }
+
+        let repair = minimal_repair(&rules, &[61, 13, 29]).unwrap();
+        assert_eq!(repair.pages, vec![61, 29, 13]);
+        assert_eq!(
+            repair.moves,
+            vec![PageMove {
+                page: 13,
+                from: 1,
+                to: 2
+            }]
+        );
+    }
+
+    #[test]
+    fn agrees_with_trying_every_subset() {
+        // only some pairs are ruled on, so kept pages can be constrained through others
+        let rules = rule_set("1|2 2|3 4|5 3|6 6|7 5|2 8|4 7|9");
+        let mut updates = vec![
+            vec![3, 2, 1],
+            vec![7, 6, 3, 2, 1],
+            vec![2, 9, 1, 5, 3],
+            vec![6, 1, 7, 2, 3, 4, 5],
+            vec![5, 4, 3, 2, 1, 6],
+        ];
+        // plus shuffled runs of pages from a small deterministic generator
+        let mut rng = Lcg::new(0x5eed);
+        for _ in 0..300 {
+            let mut pages: Vec<PageNumber> = (1..=9).collect();
+            for index in (1..pages.len()).rev() {
+                pages.swap(index, rng.below(index as u64 + 1) as usize);
+            }
+            pages.truncate(rng.below(8) as usize + 2);
+            updates.push(pages);
+        }
+
+        for instruction in updates {
+            let repair = minimal_repair(&rules, &instruction).unwrap();
+            assert!(rules.is_ordered(&repair.pages), "{:?}", repair);
+            assert!(
+                repair
+                    .moves
+                    .iter()
+                    .all(|page_move| page_move.from != page_move.to),
+                "{:?}",
+                repair
+            );
+            assert_eq!(
+                pages_out_of_order(&instruction, &repair.pages),
+                fewest_moves_by_brute_force(&rules, &instruction),
+                "{:?}",
+                instruction
+            );
+        }
+    }
+
+    #[test]
+    fn never_lists_a_page_moved_to_where_it_was() {
+        // 5 is taken out along with 6 or 4 but ends up at its own index either way
+        let rules = rule_set("4|5 5|6");
+        let repair = minimal_repair(&rules, &[6, 5, 4]).unwrap();
+        assert_eq!(repair.pages, vec![4, 5, 6]);
+        assert!(repair.moves.iter().all(|page_move| page_move.page != 5));
+
+        let rules = rule_set(EXAMPLE_RULES);
+        for instruction in [
+            vec![75, 97, 47, 61, 53],
+            vec![61, 13, 29],
+            vec![97, 13, 75, 29, 47],
+        ] {
+            let repair = minimal_repair(&rules, &instruction).unwrap();
+            assert!(
+                repair
+                    .moves
+                    .iter()
+                    .all(|page_move| page_move.from != page_move.to),
+                "{:?}",
+                repair
+            );
+        }
+    }
+
+    #[test]
+    fn refuses_contradictory_updates() {
+        let rules = rule_set("1|2 2|3 3|1");
+        assert!(minimal_repair(&rules, &[3, 2, 1]).is_err());
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt;
+
+// helper types and enums
+pub type PageNumber = u16;
+pub type PrintInstruction = Vec<PageNumber>;
+
+pub use crate::rule_set::{Cycle, Ordering, RuleSet};
+
+pub fn evaluate_instructions(
+    rule_set: &RuleSet,
+    instructions: Vec<PrintInstruction>,
+) -> (u64, u64, Vec<Contradiction>) {
+    let (valid_instructions, invalid_instructions) =
+        categorize_instructions(rule_set, instructions);
+    // repair invalid instructions, setting aside the ones that cannot be repaired
+    let (repaired_instructions, contradictions) =
+        repair_invalid_instructions(rule_set, invalid_instructions);
+
+    // parse middle values of both valid and invalid sets
+    let valid_instruction_centers: Vec<u64> = valid_instructions
+        .into_iter()
+        .map(|instruction| u64::from(instruction[instruction.len() / 2]))
+        .collect();
+    let repaired_instruction_centers: Vec<u64> = repaired_instructions
+        .into_iter()
+        .map(|instruction| u64::from(instruction[instruction.len() / 2]))
+        .collect();
+
+    // sum middle values, as u64 since a few large page numbers are already past a u16
+    let sum_of_correct = valid_instruction_centers.iter().sum::<u64>();
+    let sum_of_repaired = repaired_instruction_centers.iter().sum::<u64>();
+
+    (sum_of_correct, sum_of_repaired, contradictions)
+}
+
+pub fn categorize_instructions(
+    rule_set: &RuleSet,
+    instructions: Vec<PrintInstruction>,
+) -> (Vec<PrintInstruction>, Vec<PrintInstruction>) {
+    instructions
+        .into_iter()
+        .partition(|instruction| rule_set.is_ordered(instruction))
+}
+
+// an update whose rules contradict each other, so there is no order to repair it to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contradiction {
+    pub instruction: PrintInstruction,
+    pub cycle: Cycle,
+}
+
+impl fmt::Display for Contradiction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pages: Vec<String> = self.instruction.iter().map(PageNumber::to_string).collect();
+        write!(
+            f,
+            "cannot repair update {}, its rules form a cycle: {}",
+            pages.join(","),
+            self.cycle
+        )
+    }
+}
+
+// contradictory updates are handed back untouched rather than repaired into a wrong order
+pub fn repair_invalid_instructions(
+    rule_set: &RuleSet,
+    invalid_instruction: Vec<PrintInstruction>,
+) -> (Vec<PrintInstruction>, Vec<Contradiction>) {
+    let mut repaired_instructions = Vec::new();
+    let mut contradictions = Vec::new();
+    for instruction in &invalid_instruction {
+        match repair_instruction(rule_set, instruction) {
+            Ok(ordering) => repaired_instructions.push(ordering.pages),
+            Err(contradiction) => contradictions.push(contradiction),
+        }
+    }
+
+    (repaired_instructions, contradictions)
+}
+
+pub fn repair_instruction(
+    rule_set: &RuleSet,
+    instruction: &[PageNumber],
+) -> Result<Ordering, Contradiction> {
+    rule_set
+        .topological_order(instruction)
+        .map_err(|cycle| Contradiction {
+            instruction: instruction.to_vec(),
+            cycle,
+        })
+}
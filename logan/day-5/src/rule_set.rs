@@ -0,0 +1,278 @@
+use crate::question2::{PageNumber, PrintInstruction};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fmt;
+
+// the page ordering rules as a directed graph, with an edge X -> Y for every
+// rule `X|Y`: X has to be printed before Y whenever an update contains both
+#[derive(Debug, Clone, Default)]
+pub struct RuleSet {
+    successors: HashMap<PageNumber, Vec<PageNumber>>,
+    rules: HashSet<(PageNumber, PageNumber)>,
+    // every page mentioned on either side of a rule
+    pages: HashSet<PageNumber>,
+}
+
+// an order for an update's pages that satisfies every rule between them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ordering {
+    pub pages: PrintInstruction,
+    // false when the rules leave some pages free to swap, so other orders are just as valid
+    pub is_unique: bool,
+}
+
+// rules that loop back on themselves, e.g. `[13, 29, 13]` for `13|29` and `29|13`,
+// the first page is repeated at the end
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle(pub Vec<PageNumber>);
+
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let pages: Vec<String> = self.0.iter().map(PageNumber::to_string).collect();
+        write!(f, "{}", pages.join(" -> "))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    InProgress,
+    Done,
+}
+
+impl RuleSet {
+    pub fn new() -> Self {
+        RuleSet::default()
+    }
+
+    // records `first|second`, repeated rules are only kept once
+    pub fn add_rule(&mut self, first: PageNumber, second: PageNumber) {
+        if self.rules.insert((first, second)) {
+            self.successors.entry(first).or_default().push(second);
+            self.pages.extend([first, second]);
+        }
+    }
+
+    // whether any rule says where `page` goes
+    pub fn has_rules_for(&self, page: PageNumber) -> bool {
+        self.pages.contains(&page)
+    }
+
+    // every rule as `(first, second)`, in no particular order
+    pub fn rules(&self) -> Vec<(PageNumber, PageNumber)> {
+        self.rules.iter().copied().collect()
+    }
+
+    // the pages that have to come after `page`
+    pub fn successors(&self, page: PageNumber) -> &[PageNumber] {
+        self.successors.get(&page).map_or(&[], Vec::as_slice)
+    }
+
+    // the rules whose pages both appear in `pages`, in the order of `pages`
+    pub fn rules_within(&self, pages: &[PageNumber]) -> Vec<(PageNumber, PageNumber)> {
+        let present: HashSet<PageNumber> = pages.iter().copied().collect();
+        pages
+            .iter()
+            .flat_map(|&page| {
+                self.successors(page)
+                    .iter()
+                    .filter(|next| present.contains(next))
+                    .map(move |&next| (page, next))
+            })
+            .collect()
+    }
+
+    // whether the update as printed already satisfies every rule between its pages
+    pub fn is_ordered(&self, instruction: &[PageNumber]) -> bool {
+        let positions = positions(instruction);
+        instruction.iter().enumerate().all(|(index, &page)| {
+            self.successors(page)
+                .iter()
+                .all(|next| positions.get(next).is_none_or(|&position| position > index))
+        })
+    }
+
+    // Kahn's algorithm over just the rules between `pages`. Among the pages that
+    // are free to go next the one printed earliest is taken, so an update that is
+    // already in order comes back unchanged. If the rules between the pages form
+    // a cycle no order exists and the cycle is returned instead.
+    pub fn topological_order(&self, pages: &[PageNumber]) -> Result<Ordering, Cycle> {
+        let positions = positions(pages);
+        let mut incoming = vec![0usize; pages.len()];
+        for &page in pages {
+            for next in self.successors(page) {
+                if let Some(&position) = positions.get(next) {
+                    incoming[position] += 1;
+                }
+            }
+        }
+
+        let mut ready: BinaryHeap<Reverse<usize>> = (0..pages.len())
+            .filter(|&index| incoming[index] == 0)
+            .map(Reverse)
+            .collect();
+        let mut ordered = Vec::with_capacity(pages.len());
+        let mut is_unique = true;
+
+        while let Some(Reverse(index)) = ready.pop() {
+            // any other page that was free to go here gives a second valid order
+            if !ready.is_empty() {
+                is_unique = false;
+            }
+            ordered.push(pages[index]);
+
+            for next in self.successors(pages[index]) {
+                if let Some(&position) = positions.get(next) {
+                    incoming[position] -= 1;
+                    if incoming[position] == 0 {
+                        ready.push(Reverse(position));
+                    }
+                }
+            }
+        }
+
+        if ordered.len() < pages.len() {
+            return Err(self
+                .find_cycle_within(pages)
+                .expect("Kahn's algorithm only stalls on a cycle"));
+        }
+
+        Ok(Ordering {
+            pages: ordered,
+            is_unique,
+        })
+    }
+
+    // a cycle anywhere in the rules; the puzzle's rules usually have some, which
+    // only matters for updates that contain every page of one
+    pub fn find_cycle(&self) -> Option<Cycle> {
+        let mut pages: Vec<PageNumber> = self.successors.keys().copied().collect();
+        pages.sort_unstable();
+        self.search_for_cycle(&pages, |_| true)
+    }
+
+    // a cycle using only the rules between `pages`, which makes them impossible to order
+    pub fn find_cycle_within(&self, pages: &[PageNumber]) -> Option<Cycle> {
+        let present: HashSet<PageNumber> = pages.iter().copied().collect();
+        self.search_for_cycle(pages, |page| present.contains(&page))
+    }
+
+    // depth-first search from each start in turn, a rule leading back to a page
+    // still on the current path closes a cycle
+    fn search_for_cycle(
+        &self,
+        starts: &[PageNumber],
+        is_included: impl Fn(PageNumber) -> bool,
+    ) -> Option<Cycle> {
+        let mut visits: HashMap<PageNumber, Visit> = HashMap::new();
+
+        for &start in starts {
+            if visits.contains_key(&start) {
+                continue;
+            }
+            visits.insert(start, Visit::InProgress);
+            // each page on the path with how many of its successors have been followed
+            let mut path: Vec<(PageNumber, usize)> = vec![(start, 0)];
+
+            while let Some((page, followed)) = path.last_mut() {
+                let Some(&next) = self.successors(*page).get(*followed) else {
+                    visits.insert(*page, Visit::Done);
+                    path.pop();
+                    continue;
+                };
+                *followed += 1;
+
+                if !is_included(next) {
+                    continue;
+                }
+                match visits.get(&next) {
+                    Some(Visit::InProgress) => {
+                        let from = path.iter().position(|(page, _)| *page == next)?;
+                        let mut cycle: Vec<PageNumber> =
+                            path[from..].iter().map(|(page, _)| *page).collect();
+                        cycle.push(next);
+                        return Some(Cycle(cycle));
+                    }
+                    Some(Visit::Done) => {}
+                    None => {
+                        visits.insert(next, Visit::InProgress);
+                        path.push((next, 0));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
+// pages within an update are distinct, `Day5::parse` rejects repeats
+fn positions(pages: &[PageNumber]) -> HashMap<PageNumber, usize> {
+    pages
+        .iter()
+        .enumerate()
+        .map(|(index, &page)| (page, index))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule_set(rules: &[(PageNumber, PageNumber)]) -> RuleSet {
+        let mut rule_set = RuleSet::new();
+        for &(first, second) in rules {
+            rule_set.add_rule(first, second);
+        }
+        rule_set
+    }
+
+    #[test]
+    fn orders_pages_from_a_partial_set_of_rules() {
+        // only a chain, nothing says where 40 goes relative to 10 and 20 other than before 30
+        let rules = rule_set(&[(10, 20), (20, 30), (40, 30)]);
+
+        let ordering = rules.topological_order(&[30, 20, 40, 10]).unwrap();
+        assert_eq!(ordering.pages, vec![40, 10, 20, 30]);
+        assert!(!ordering.is_unique);
+        assert!(rules.is_ordered(&ordering.pages));
+
+        let unique = rules.topological_order(&[30, 20, 10]).unwrap();
+        assert_eq!(unique.pages, vec![10, 20, 30]);
+        assert!(unique.is_unique);
+    }
+
+    #[test]
+    fn leaves_ordered_updates_alone() {
+        let rules = rule_set(&[(1, 2), (3, 4)]);
+
+        assert!(rules.is_ordered(&[3, 1, 4, 2]));
+        assert_eq!(
+            rules.topological_order(&[3, 1, 4, 2]).unwrap().pages,
+            vec![3, 1, 4, 2]
+        );
+        assert!(!rules.is_ordered(&[2, 1]));
+    }
+
+    #[test]
+    fn reports_the_cycle_instead_of_an_order() {
+        let rules = rule_set(&[(1, 2), (2, 3), (3, 1), (3, 4)]);
+
+        assert_eq!(
+            rules.topological_order(&[4, 1, 2, 3]),
+            Err(Cycle(vec![1, 2, 3, 1]))
+        );
+        assert_eq!(Cycle(vec![1, 2, 3, 1]).to_string(), "1 -> 2 -> 3 -> 1");
+        // the cycle only matters when all of its pages are printed together
+        assert!(rules.topological_order(&[1, 3]).is_ok());
+        assert_eq!(rules.find_cycle_within(&[1, 3, 4]), None);
+    }
+
+    #[test]
+    fn finds_cycles_across_the_whole_rule_set() {
+        let acyclic = rule_set(&[(1, 2), (1, 3), (2, 4), (3, 4)]);
+        assert_eq!(acyclic.find_cycle(), None);
+
+        let cyclic = rule_set(&[(5, 6), (6, 7), (7, 8), (8, 6)]);
+        assert_eq!(cyclic.find_cycle(), Some(Cycle(vec![6, 7, 8, 6])));
+    }
+}
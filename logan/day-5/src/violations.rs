@@ -0,0 +1,163 @@
+use crate::question2::{PageNumber, RuleSet};
+use aoc_core::Json;
+
+// a rule `first|second` the update breaks by printing `second` before `first`,
+// positions are 0-based
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BrokenRule {
+    pub first: PageNumber,
+    pub second: PageNumber,
+    pub first_position: usize,
+    pub second_position: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViolationReport {
+    pub broken_rules: Vec<BrokenRule>,
+    // pages no rule mentions at all, so nothing says where they belong
+    pub unruled_pages: Vec<PageNumber>,
+}
+
+impl ViolationReport {
+    pub fn is_empty(&self) -> bool {
+        self.broken_rules.is_empty() && self.unruled_pages.is_empty()
+    }
+
+    // `number` and `line` say which update this is, positions are shown 1-based
+    pub fn to_text(&self, number: usize, line: usize, instruction: &[PageNumber]) -> String {
+        let pages: Vec<String> = instruction.iter().map(PageNumber::to_string).collect();
+        let mut text = format!("update {} (line {}): {}", number, line, pages.join(","));
+
+        for rule in &self.broken_rules {
+            text += &format!(
+                "\n  breaks {}|{}: {} is at position {}, {} at position {}",
+                rule.first,
+                rule.second,
+                rule.first,
+                rule.first_position + 1,
+                rule.second,
+                rule.second_position + 1
+            );
+        }
+        if !self.unruled_pages.is_empty() {
+            let pages: Vec<String> = self
+                .unruled_pages
+                .iter()
+                .map(PageNumber::to_string)
+                .collect();
+            text += &format!("\n  no rules for pages: {}", pages.join(", "));
+        }
+
+        text
+    }
+
+    pub fn to_json(&self, number: usize, line: usize, instruction: &[PageNumber]) -> Json {
+        let broken_rules: Vec<Json> = self
+            .broken_rules
+            .iter()
+            .map(|rule| {
+                Json::object()
+                    .with("rule", format!("{}|{}", rule.first, rule.second))
+                    .with("first", rule.first)
+                    .with("second", rule.second)
+                    .with("first_position", rule.first_position + 1)
+                    .with("second_position", rule.second_position + 1)
+            })
+            .collect();
+
+        Json::object()
+            .with("update", number)
+            .with("line", line)
+            .with("pages", instruction.to_vec())
+            .with("broken_rules", broken_rules)
+            .with("unruled_pages", self.unruled_pages.clone())
+    }
+}
+
+// every rule between the update's pages that it prints the wrong way round, in
+// the order of the rule's first page within the update
+pub fn violation_report(rule_set: &RuleSet, instruction: &[PageNumber]) -> ViolationReport {
+    let position = |page: PageNumber| instruction.iter().position(|&other| other == page);
+
+    let broken_rules = rule_set
+        .rules_within(instruction)
+        .into_iter()
+        .filter_map(|(first, second)| {
+            let (first_position, second_position) = (position(first)?, position(second)?);
+            (first_position > second_position).then_some(BrokenRule {
+                first,
+                second,
+                first_position,
+                second_position,
+            })
+        })
+        .collect();
+
+    let unruled_pages = instruction
+        .iter()
+        .copied()
+        .filter(|&page| !rule_set.has_rules_for(page))
+        .collect();
+
+    ViolationReport {
+        broken_rules,
+        unruled_pages,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_broken_rules_and_unruled_pages() {
+        let mut rule_set = RuleSet::new();
+        rule_set.add_rule(97, 75);
+        rule_set.add_rule(75, 13);
+        rule_set.add_rule(97, 13);
+
+        let report = violation_report(&rule_set, &[13, 75, 40, 97]);
+        assert_eq!(
+            report.broken_rules,
+            vec![
+                BrokenRule {
+                    first: 75,
+                    second: 13,
+                    first_position: 1,
+                    second_position: 0
+                },
+                BrokenRule {
+                    first: 97,
+                    second: 75,
+                    first_position: 3,
+                    second_position: 1
+                },
+                BrokenRule {
+                    first: 97,
+                    second: 13,
+                    first_position: 3,
+                    second_position: 0
+                },
+            ]
+        );
+        assert_eq!(report.unruled_pages, vec![40]);
+        assert!(violation_report(&rule_set, &[97, 75, 13]).is_empty());
+    }
+
+    #[test]
+    fn renders_text_and_json() {
+        let mut rule_set = RuleSet::new();
+        rule_set.add_rule(97, 75);
+        let instruction = [75, 97, 5];
+        let report = violation_report(&rule_set, &instruction);
+
+        assert_eq!(
+            report.to_text(2, 24, &instruction),
+            "update 2 (line 24): 75,97,5\n  breaks 97|75: 97 is at position 2, 75 at position 1\n  no rules for pages: 5"
+        );
+        assert_eq!(
+            report.to_json(2, 24, &instruction).to_string(),
+            r#"{"update":2,"line":24,"pages":[75,97,5],"broken_rules":[{"rule":"97|75","first":97,"second":75,"first_position":2,"second_position":1}],"unruled_pages":[5]}"#
+        );
+    }
+}
@@ -0,0 +1,36 @@
+use aoc_core::{Answer, AocError, Solution};
+use aoc_grid::{Grid, Point};
+
+pub mod question1;
+pub mod question2;
+
+pub struct Day6;
+
+pub struct Lab {
+    pub grid: Grid<char>,
+    pub starting_pos: Point,
+}
+
+impl Solution for Day6 {
+    type Input = Lab;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        let grid = Grid::parse(input)?;
+        let starting_pos =
+            question1::find_starting_position(&grid).ok_or(AocError::MissingStart {
+                marker: question1::GUARD,
+            })?;
+
+        Ok(Lab { grid, starting_pos })
+    }
+
+    fn part1(lab: &Self::Input) -> Answer {
+        question1::traverse_grid(&lab.grid, lab.starting_pos)
+            .len()
+            .into()
+    }
+
+    fn part2(lab: &Self::Input) -> Answer {
+        question2::count_loop_positions(&lab.grid, lab.starting_pos).into()
+    }
+}
@@ -0,0 +1,42 @@
+use aoc_grid::{Direction, Grid, Point};
+use std::collections::HashSet;
+
+pub type CoordSet = HashSet<Point>;
+
+pub const BLOCKER: char = '#';
+pub const GUARD: char = '^';
+
+pub fn find_starting_position(grid: &Grid<char>) -> Option<Point> {
+    grid.position(|&char| char == GUARD)
+}
+
+pub fn traverse_grid(grid: &Grid<char>, starting_pos: Point) -> CoordSet {
+    let mut visited_coords: CoordSet = HashSet::from([starting_pos]);
+    let mut movement_direction = Direction::North;
+    let mut current_position = starting_pos;
+
+    /* Algorithm:
+       define a set of 'visited coordinates' that starts with starting position
+       - while: check to see if next movement position is in bounds
+           - true?
+             check to see if its a blocker
+               - true? change direction, continue to next iteration
+               - false?
+                   set current_position = next position
+                   add current_position to visited_coords
+           - false?
+               break loop and begin to sum
+    */
+    while let Some(next_coord) = grid.step(current_position, movement_direction) {
+        let is_next_blocked = grid[next_coord] == BLOCKER;
+        if is_next_blocked {
+            movement_direction = movement_direction.turn_right();
+            continue;
+        }
+
+        current_position = next_coord;
+        visited_coords.insert(current_position);
+    }
+
+    visited_coords
+}
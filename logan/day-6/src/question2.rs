@@ -0,0 +1,41 @@
+use crate::question1::{traverse_grid, BLOCKER};
+use aoc_grid::{Direction, Grid, Point};
+
+/*
+   Counts the spots where adding a single blocker traps the guard in a loop.
+   - a new blocker only changes the route if the guard would have walked over it,
+     so only the spots visited in question 1 need trying (minus the starting spot)
+   - the guard is in a loop once it stands on the same spot facing the same direction twice
+*/
+pub fn count_loop_positions(grid: &Grid<char>, starting_pos: Point) -> usize {
+    traverse_grid(grid, starting_pos)
+        .into_iter()
+        .filter(|candidate| *candidate != starting_pos)
+        .filter(|candidate| is_guard_looping(grid, starting_pos, *candidate))
+        .count()
+}
+
+pub fn is_guard_looping(grid: &Grid<char>, starting_pos: Point, extra_blocker: Point) -> bool {
+    // one bit per direction the guard has faced on each spot
+    let mut faced_directions: Grid<u8> = grid.map(|_| 0);
+    let mut movement_direction = Direction::North;
+    let mut current_position = starting_pos;
+
+    loop {
+        let direction_bit = 1 << movement_direction as u8;
+        if faced_directions[current_position] & direction_bit != 0 {
+            return true;
+        }
+        faced_directions[current_position] |= direction_bit;
+
+        let Some(next_coord) = grid.step(current_position, movement_direction) else {
+            return false;
+        };
+
+        if grid[next_coord] == BLOCKER || next_coord == extra_blocker {
+            movement_direction = movement_direction.turn_right();
+        } else {
+            current_position = next_coord;
+        }
+    }
+}
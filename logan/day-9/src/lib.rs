@@ -0,0 +1,46 @@
+use aoc_core::{exit_with, read_input_or_exit, Answer, AocError, Solution};
+use input_read_util::{parse_digits, ParseError};
+
+pub mod question1;
+pub mod question2;
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    // the compact disk map, one digit per block count
+    type Input = Vec<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_digits(input)?)
+    }
+
+    fn part1(disk_map: &Self::Input) -> Answer {
+        question1::orchetrate_disk_cleanup(disk_map).into()
+    }
+
+    fn part2(disk_map: &Self::Input) -> Answer {
+        question2::orchetrate_disk_cleanup(disk_map).into()
+    }
+}
+
+// shared by the question1 and question2 bins, which only differ in how they clean up the disk
+pub fn run_cli(args: &[String], orchetrate_disk_cleanup: fn(&[u8]) -> i64, label: &str) {
+    let file_path = args.first().map(String::as_str);
+    let input = read_input_or_exit(file_path);
+
+    for (index, disk_map) in input.lines().enumerate() {
+        // every line is its own disk map, so point errors at the line they came from
+        let disk_map = match parse_digits(disk_map) {
+            Ok(disk_map) => disk_map,
+            Err(error) => exit_with(
+                ParseError {
+                    line: index + 1,
+                    ..error
+                }
+                .into(),
+            ),
+        };
+        let sum = orchetrate_disk_cleanup(&disk_map);
+        println!("{}: {}", label, sum)
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::VecDeque;
+
+pub fn orchetrate_disk_cleanup(disk_map: &[u8]) -> i64 {
+    let (expanded_map, file_disk_space) = expand_disk_map(disk_map);
+
+    let reordered_map = re_order_disk(expanded_map, file_disk_space);
+
+    checksum(reordered_map)
+}
+
+// takes compact disk map and expands values into better representations of what it meansturns it into format -> ##.....#####...##..#.##...
+fn expand_disk_map(disk_map: &[u8]) -> (Vec<String>, i32) {
+    // replace every even index (or 0-index) with a number of chars (whose value is the file's 0-index by order of appearence)
+
+    // replace each odd index with a number of '.' equal to the value of the source char
+
+    let mut expanded_disk_map = vec![];
+    let mut total_file_disk_space = 0;
+    for (i, num) in disk_map.iter().enumerate() {
+        let mut expanded_representation = match i % 2 {
+            0 => {
+                let parsed_num = *num as i32;
+                let file_index: usize = i / 2;
+
+                total_file_disk_space += parsed_num;
+                vec![file_index.to_string(); parsed_num as usize]
+            }
+            1 => {
+                vec![String::from("."); *num as usize]
+            }
+            _ => vec![],
+        };
+
+        expanded_disk_map.append(&mut expanded_representation);
+    }
+
+    (expanded_disk_map, total_file_disk_space)
+}
+
+/*
+   Algorithm:
+   - keep a vecdeque for all numbers of format VecDeque<(source_arr_index, char_value)>
+   - pop_back from numbers, pop_front for free space
+   - swap popped values,
+   - repeat until all free spaces are contiguous
+*/
+fn re_order_disk(expanded_map: Vec<String>, total_file_disk_space: i32) -> Vec<String> {
+    let mut empty_disk_space: VecDeque<usize> = VecDeque::new();
+    let mut reversed_file_locations: VecDeque<usize> = VecDeque::new();
+
+    for (i, slot_value) in expanded_map.iter().enumerate() {
+        let is_empty_space = slot_value.contains(".");
+        if is_empty_space {
+            // we do not care about free space that occurs after the point where, once sorted, all file data will be stored
+            if i < total_file_disk_space as usize {
+                empty_disk_space.push_back(i);
+            }
+        } else {
+            reversed_file_locations.push_front(i);
+        }
+    }
+
+    let mut map_clone = expanded_map.clone();
+    while !empty_disk_space.is_empty() {
+        let first_open_disk_slot = empty_disk_space.pop_front().unwrap();
+        let last_file_disk_slot = reversed_file_locations.pop_front().unwrap();
+
+        map_clone.swap(last_file_disk_slot, first_open_disk_slot);
+    }
+
+    map_clone
+}
+
+fn checksum(disk_map: Vec<String>) -> i64 {
+    let mut sum: i64 = 0;
+    for (i, char) in disk_map.iter().enumerate() {
+        let Ok(parsed_char) = char.parse::<i64>() else {
+            continue;
+        };
+
+        sum += parsed_char * (i as i64)
+    }
+
+    sum
+}
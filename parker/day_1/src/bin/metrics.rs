@@ -0,0 +1,84 @@
+use aoc_core::{exit_with, open_input_or_exit, AocError};
+use input_read_util::input_name;
+use parker_day_1::{metric_matrix, read_columns, Metric, MetricValue};
+use std::env;
+
+const USAGE: &str = "metrics <all | metric[,metric...]> [<path_to_input> | -]";
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+
+    if args.len() < 2 || args.len() > 3 {
+        exit_with(AocError::Usage(format!(
+            "Invalid number of args\n{}",
+            USAGE
+        )));
+    }
+
+    let metrics: Vec<Metric> = if args[1] == "all" {
+        Metric::ALL.to_vec()
+    } else {
+        match args[1].split(',').map(str::parse).collect() {
+            Ok(metrics) => metrics,
+            Err(error) => exit_with(AocError::Usage(format!("{}\n{}", error, USAGE))),
+        }
+    };
+
+    let input_path = args.get(2).map(String::as_str);
+
+    let reader = open_input_or_exit(input_path);
+
+    let columns = match read_columns(reader, input_name(input_path)) {
+        Ok(columns) => columns,
+        Err(error) => exit_with(error),
+    };
+
+    // the usual two lists get one line per metric, anything wider gets a matrix per metric
+    if columns.len() == 2 {
+        for metric in metrics {
+            println!(
+                "{}: {}",
+                metric.name(),
+                format_value(metric.compute(&columns[0], &columns[1]))
+            );
+        }
+        return;
+    }
+
+    for metric in metrics {
+        let matrix = metric_matrix(metric, &columns);
+        let cells: Vec<Vec<String>> = matrix
+            .iter()
+            .map(|row| row.iter().map(|value| format_value(*value)).collect())
+            .collect();
+        let width = cells
+            .iter()
+            .flatten()
+            .map(String::len)
+            .chain([format!("col {}", columns.len()).len()])
+            .max()
+            .unwrap_or(0);
+
+        println!("{}", metric.name());
+        print!("{:>width$}", "", width = width);
+        for column in 1..=columns.len() {
+            print!("  {:>width$}", format!("col {}", column), width = width);
+        }
+        println!();
+        for (row, values) in cells.iter().enumerate() {
+            print!("{:>width$}", format!("col {}", row + 1), width = width);
+            for value in values {
+                print!("  {:>width$}", value, width = width);
+            }
+            println!();
+        }
+        println!();
+    }
+}
+
+fn format_value(value: Option<MetricValue>) -> String {
+    match value {
+        Some(value) => value.to_string(),
+        None => String::from("overflow"),
+    }
+}
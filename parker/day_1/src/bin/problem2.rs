@@ -0,0 +1,10 @@
+use parker_day_1::{run_cli, Metric};
+use std::env;
+
+fn main() {
+    run_cli(
+        &env::args().skip(1).collect::<Vec<_>>(),
+        Metric::Similarity,
+        "problem2",
+    );
+}
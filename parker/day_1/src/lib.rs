@@ -0,0 +1,168 @@
+use aoc_core::{exit_with, open_input_or_exit, Answer, AocError, Solution};
+use input_read_util::{input_name, parse_int_row, ParseError, Separator};
+use std::io::BufRead;
+
+mod metrics;
+mod sort;
+
+pub use metrics::{metric_matrix, Metric, MetricValue};
+pub use sort::radix_sort;
+
+pub struct Day1;
+
+// one Vec per column of the input, all the same length
+pub type Columns = Vec<Vec<i64>>;
+
+impl Solution for Day1 {
+    type Input = Columns;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        // the puzzle compares the first two lists, any further columns are ignored
+        Ok(require_columns(parse_columns(input)?, 2)?)
+    }
+
+    fn part1(columns: &Self::Input) -> Answer {
+        Metric::SortedL1
+            .compute(&columns[0], &columns[1])
+            .expect("the sum of 64-bit differences fits in 128 bits")
+            .into()
+    }
+
+    fn part2(columns: &Self::Input) -> Answer {
+        Metric::Similarity
+            .compute(&columns[0], &columns[1])
+            .expect("the similarity of 64-bit ids fits in 128 bits")
+            .into()
+    }
+}
+
+pub fn parse_columns(input: &str) -> Result<Columns, ParseError> {
+    let mut builder = ColumnsBuilder::default();
+
+    for (index, line) in input.lines().enumerate() {
+        builder.push_line(line, index + 1)?;
+    }
+
+    Ok(builder.columns)
+}
+
+// same as parse_columns, but reads one line at a time so the raw input never has to be held in memory
+pub fn read_columns(mut reader: impl BufRead, input_name: &str) -> Result<Columns, AocError> {
+    let mut builder = ColumnsBuilder::default();
+
+    let mut line = String::new();
+    let mut line_number = 0;
+
+    loop {
+        line.clear();
+        let bytes_read = reader
+            .read_line(&mut line)
+            .map_err(|error| AocError::io(input_name, error))?;
+        if bytes_read == 0 {
+            break;
+        }
+        line_number += 1;
+
+        builder.push_line(&line, line_number)?;
+    }
+
+    Ok(builder.columns)
+}
+
+pub fn require_columns(columns: Columns, min_columns: usize) -> Result<Columns, ParseError> {
+    if columns.len() < min_columns {
+        return Err(ParseError::new(
+            1,
+            1,
+            format!(
+                "expected at least {} columns, found {}",
+                min_columns,
+                columns.len()
+            ),
+        ));
+    }
+    Ok(columns)
+}
+
+// shared by the problem1 and problem2 bins, which only differ in the metric they print
+pub fn run_cli(args: &[String], metric: Metric, name: &str) {
+    if args.len() > 1 {
+        exit_with(AocError::Usage(format!(
+            "Invalid number of args\n{} [<path_to_input> | -]",
+            name
+        )));
+    }
+
+    let input_path = args.first().map(String::as_str);
+
+    let reader = open_input_or_exit(input_path);
+
+    // read line by line so very large lists don't need a copy of the raw input
+    let columns = match read_columns(reader, input_name(input_path)) {
+        Ok(columns) => columns,
+        Err(error) => exit_with(error),
+    };
+    let columns = match require_columns(columns, 2) {
+        Ok(columns) => columns,
+        Err(error) => exit_with(error.into()),
+    };
+
+    let value = metric
+        .compute(&columns[0], &columns[1])
+        .expect("the puzzle metrics of 64-bit values fit in 128 bits");
+    println!("{}", value);
+}
+
+// the first non-blank line decides how many columns every other line needs
+#[derive(Default)]
+struct ColumnsBuilder {
+    columns: Columns,
+    first_line: usize,
+}
+
+impl ColumnsBuilder {
+    // blank lines are skipped
+    fn push_line(&mut self, line: &str, line_number: usize) -> Result<(), ParseError> {
+        let line = line.trim_end();
+        if line.is_empty() {
+            return Ok(());
+        }
+
+        let row: Vec<i64> = parse_int_row(line, line_number, Separator::Whitespace)?;
+
+        if self.columns.is_empty() {
+            self.columns = vec![Vec::new(); row.len()];
+            self.first_line = line_number;
+        }
+
+        let expected = self.columns.len();
+        if row.len() != expected {
+            // point at the first extra number, or just past the end when one is missing
+            let column = match line.split_whitespace().nth(expected) {
+                Some(extra) => {
+                    line[..extra.as_ptr() as usize - line.as_ptr() as usize]
+                        .chars()
+                        .count()
+                        + 1
+                }
+                None => line.chars().count() + 1,
+            };
+            return Err(ParseError::new(
+                line_number,
+                column,
+                format!(
+                    "expected {} numbers like line {}, found {}",
+                    expected,
+                    self.first_line,
+                    row.len()
+                ),
+            ));
+        }
+
+        for (column, value) in self.columns.iter_mut().zip(row) {
+            column.push(value);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,233 @@
+use crate::radix_sort;
+use aoc_core::Answer;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+// ways of comparing two parsed columns, part 1 is `SortedL1` and part 2 is `Similarity`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    // sum of |a - b| after pairing both columns up smallest to smallest
+    SortedL1,
+    // sum of (a - b)^2 over the same sorted pairs
+    SquaredDistance,
+    // every left value times how often it shows up on the right
+    Similarity,
+    // shared distinct values over all distinct values, 1 for two empty columns
+    Jaccard,
+    // how many values the columns have in common, counting repeats
+    Intersection,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MetricValue {
+    Integer(i128),
+    Ratio(f64),
+}
+
+impl Metric {
+    pub const ALL: [Metric; 5] = [
+        Metric::SortedL1,
+        Metric::SquaredDistance,
+        Metric::Similarity,
+        Metric::Jaccard,
+        Metric::Intersection,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Metric::SortedL1 => "l1",
+            Metric::SquaredDistance => "squared",
+            Metric::Similarity => "similarity",
+            Metric::Jaccard => "jaccard",
+            Metric::Intersection => "intersection",
+        }
+    }
+
+    // None when the result does not fit in 128 bits, which only squared distance can realistically hit
+    pub fn compute(self, left_nums: &[i64], right_nums: &[i64]) -> Option<MetricValue> {
+        match self {
+            Metric::SortedL1 => sorted_pairs_sum(left_nums, right_nums, |dif| Some(dif as i128)),
+            Metric::SquaredDistance => sorted_pairs_sum(left_nums, right_nums, |dif| {
+                (dif as i128).checked_mul(dif as i128)
+            }),
+            Metric::Similarity => similarity_score(left_nums, right_nums),
+            Metric::Jaccard => Some(jaccard_overlap(left_nums, right_nums)),
+            Metric::Intersection => Some(intersection_size(left_nums, right_nums)),
+        }
+    }
+}
+
+// compares every column with every column, `matrix[i][j]` is the metric of column i against column j
+pub fn metric_matrix(metric: Metric, columns: &[Vec<i64>]) -> Vec<Vec<Option<MetricValue>>> {
+    columns
+        .iter()
+        .map(|left_nums| {
+            columns
+                .iter()
+                .map(|right_nums| metric.compute(left_nums, right_nums))
+                .collect()
+        })
+        .collect()
+}
+
+impl FromStr for Metric {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Metric::ALL
+            .into_iter()
+            .find(|metric| metric.name() == name)
+            .ok_or_else(|| {
+                let names: Vec<&str> = Metric::ALL.iter().map(|metric| metric.name()).collect();
+                format!(
+                    "unknown metric '{}', expected one of {}",
+                    name,
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+impl fmt::Display for MetricValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MetricValue::Integer(value) => write!(f, "{}", value),
+            MetricValue::Ratio(value) => write!(f, "{:.6}", value),
+        }
+    }
+}
+
+impl From<MetricValue> for Answer {
+    fn from(value: MetricValue) -> Self {
+        match value {
+            MetricValue::Integer(value) => value.into(),
+            MetricValue::Ratio(_) => value.to_string().into(),
+        }
+    }
+}
+
+fn sorted_pairs_sum(
+    left_nums: &[i64],
+    right_nums: &[i64],
+    pair_cost: impl Fn(u64) -> Option<i128>,
+) -> Option<MetricValue> {
+    let mut left_nums = left_nums.to_vec();
+    let mut right_nums = right_nums.to_vec();
+    radix_sort(&mut left_nums);
+    radix_sort(&mut right_nums);
+
+    let mut dif: i128 = 0;
+
+    for (a, b) in left_nums.iter().zip(right_nums.iter()) {
+        dif = dif.checked_add(pair_cost(a.abs_diff(*b))?)?;
+    }
+
+    Some(MetricValue::Integer(dif))
+}
+
+fn count_values(nums: &[i64]) -> HashMap<i64, u64> {
+    let mut counts: HashMap<i64, u64> = HashMap::new();
+
+    for num in nums {
+        *counts.entry(*num).or_insert(0) += 1;
+    }
+
+    counts
+}
+
+fn similarity_score(left_nums: &[i64], right_nums: &[i64]) -> Option<MetricValue> {
+    let right_nums_map = count_values(right_nums);
+
+    let mut dif: i128 = 0;
+
+    for num in left_nums {
+        let times_seen = right_nums_map.get(num).copied().unwrap_or(0);
+        dif = dif.checked_add((*num as i128).checked_mul(times_seen as i128)?)?;
+    }
+
+    Some(MetricValue::Integer(dif))
+}
+
+fn jaccard_overlap(left_nums: &[i64], right_nums: &[i64]) -> MetricValue {
+    let left_counts = count_values(left_nums);
+    let right_counts = count_values(right_nums);
+
+    let shared = left_counts
+        .keys()
+        .filter(|num| right_counts.contains_key(num))
+        .count();
+    let total = left_counts.len() + right_counts.len() - shared;
+
+    if total == 0 {
+        return MetricValue::Ratio(1.0);
+    }
+    MetricValue::Ratio(shared as f64 / total as f64)
+}
+
+fn intersection_size(left_nums: &[i64], right_nums: &[i64]) -> MetricValue {
+    let right_counts = count_values(right_nums);
+
+    let shared: u64 = count_values(left_nums)
+        .iter()
+        .map(|(num, count)| *count.min(right_counts.get(num).unwrap_or(&0)))
+        .sum();
+
+    MetricValue::Integer(shared as i128)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LEFT: [i64; 6] = [3, 4, 2, 1, 3, 3];
+    const RIGHT: [i64; 6] = [4, 3, 5, 3, 9, 3];
+
+    #[test]
+    fn computes_every_metric_for_the_example() {
+        let compute = |metric: Metric| metric.compute(&LEFT, &RIGHT).unwrap();
+
+        assert_eq!(compute(Metric::SortedL1), MetricValue::Integer(11));
+        // sorted pairs differ by 2, 1, 0, 1, 2, 5
+        assert_eq!(compute(Metric::SquaredDistance), MetricValue::Integer(35));
+        assert_eq!(compute(Metric::Similarity), MetricValue::Integer(31));
+        // {1, 2, 3, 4} and {3, 4, 5, 9} share 3 and 4
+        assert_eq!(compute(Metric::Jaccard), MetricValue::Ratio(2.0 / 6.0));
+        // three 3s on each side plus one 4
+        assert_eq!(compute(Metric::Intersection), MetricValue::Integer(4));
+    }
+
+    #[test]
+    fn reports_overflow_instead_of_wrapping() {
+        assert_eq!(
+            Metric::SquaredDistance.compute(&[i64::MIN], &[i64::MAX]),
+            None
+        );
+        assert_eq!(
+            Metric::SortedL1.compute(&[i64::MIN], &[i64::MAX]),
+            Some(MetricValue::Integer(u64::MAX as i128))
+        );
+    }
+
+    #[test]
+    fn compares_every_pair_of_columns() {
+        let columns = vec![LEFT.to_vec(), RIGHT.to_vec(), vec![1, 1, 1, 1, 1, 1]];
+        let matrix = metric_matrix(Metric::Similarity, &columns);
+
+        assert_eq!(matrix.len(), 3);
+        assert_eq!(matrix[0][1], Some(MetricValue::Integer(31)));
+        assert_eq!(matrix[1][0], matrix[0][1]);
+        // the single 1 on the left matches all six 1s
+        assert_eq!(matrix[0][2], Some(MetricValue::Integer(6)));
+        // a column against itself: 3 * 3 * 3 + 4 + 2 + 1
+        assert_eq!(matrix[0][0], Some(MetricValue::Integer(34)));
+    }
+
+    #[test]
+    fn parses_metric_names() {
+        for metric in Metric::ALL {
+            assert_eq!(metric.name().parse::<Metric>(), Ok(metric));
+        }
+        assert!("cosine".parse::<Metric>().is_err());
+    }
+}
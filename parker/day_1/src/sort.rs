@@ -0,0 +1,64 @@
+// LSD radix sort over the bytes of each value. It makes at most 8 linear passes no
+// matter how many values there are, so millions of location ids sort without
+// a comparison sort's n log n.
+
+const SIGN_BIT: u64 = 1 << 63;
+const RADIX: usize = 256;
+
+pub fn radix_sort(values: &mut [i64]) {
+    // flipping the sign bit makes negative numbers order before positive ones as unsigned keys
+    let mut keys: Vec<u64> = values
+        .iter()
+        .map(|value| (*value as u64) ^ SIGN_BIT)
+        .collect();
+    let mut sorted_keys: Vec<u64> = vec![0; keys.len()];
+
+    for pass in 0..8 {
+        let shift = pass * 8;
+        let digit = |key: u64| ((key >> shift) & 0xff) as usize;
+
+        let mut counts = [0usize; RADIX];
+        for key in &keys {
+            counts[digit(*key)] += 1;
+        }
+
+        // every key shares this byte (common for small ids), so the pass would not move anything
+        if counts.contains(&keys.len()) {
+            continue;
+        }
+
+        let mut offset = 0;
+        for count in counts.iter_mut() {
+            let bucket_size = *count;
+            *count = offset;
+            offset += bucket_size;
+        }
+
+        for key in &keys {
+            let bucket = &mut counts[digit(*key)];
+            sorted_keys[*bucket] = *key;
+            *bucket += 1;
+        }
+
+        std::mem::swap(&mut keys, &mut sorted_keys);
+    }
+
+    for (value, key) in values.iter_mut().zip(keys) {
+        *value = (key ^ SIGN_BIT) as i64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorts_like_a_comparison_sort() {
+        let mut values = vec![3, -4, 2, i64::MAX, 0, 300, -300, i64::MIN, 3, 65_536, -1];
+        let mut expected = values.clone();
+        expected.sort();
+
+        radix_sort(&mut values);
+        assert_eq!(values, expected);
+    }
+}
@@ -0,0 +1,39 @@
+use aoc_core::{exit_with, read_input_or_exit, AocError};
+use parker_day_2::{is_level_valid, parse_reports, SafetyRules, RULE_FLAGS};
+use std::env;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let usage = format!("problem1 {} [<path_to_input> | -]", RULE_FLAGS);
+
+    let (rules, positional) = match SafetyRules::from_args(&args) {
+        Ok(parsed) => parsed,
+        Err(AocError::Usage(message)) => {
+            exit_with(AocError::Usage(format!("{}\n{}", message, usage)))
+        }
+        Err(error) => exit_with(error),
+    };
+
+    if positional.len() > 1 {
+        exit_with(AocError::Usage(format!("Invalid number of args\n{}", usage)));
+    }
+
+    let input_path = positional.first().map(String::as_str);
+
+    let input = read_input_or_exit(input_path);
+
+    let reports = match parse_reports(&input) {
+        Ok(reports) => reports,
+        Err(error) => exit_with(error.into()),
+    };
+
+    let mut num_success: i32 = 0;
+
+    for level in reports {
+        if is_level_valid(&level, &rules) {
+            num_success+=1;
+        }
+    }
+
+    println!("{}", num_success);
+}
@@ -0,0 +1,100 @@
+use aoc_core::{exit_with, read_input_or_exit, AocError, Json};
+use parker_day_2::{
+    explain_report, is_report_safe_with_removals, parse_reports, SafetyRules, RULE_FLAGS,
+};
+use std::env;
+
+// the puzzle's Problem Dampener removes at most one level
+const DEFAULT_MAX_REMOVALS: usize = 1;
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let usage = format!(
+        "problem2 {} [--explain text|json] [<path_to_input> | -] [<max_removals>]",
+        RULE_FLAGS
+    );
+
+    // --explain belongs to this binary rather than the safety rules, so take it out first
+    let explain = match args.iter().position(|arg| arg == "--explain") {
+        None => None,
+        Some(index) => match args.get(index + 1).map(String::as_str) {
+            Some(format @ ("text" | "json")) => {
+                let format = format.to_string();
+                args.drain(index..index + 2);
+                Some(format)
+            }
+            _ => exit_with(AocError::Usage(format!(
+                "--explain expects text or json\n{}",
+                usage
+            ))),
+        },
+    };
+
+    let (rules, positional) = match SafetyRules::from_args(&args) {
+        Ok(parsed) => parsed,
+        Err(AocError::Usage(message)) => {
+            exit_with(AocError::Usage(format!("{}\n{}", message, usage)))
+        }
+        Err(error) => exit_with(error),
+    };
+
+    if positional.len() > 2 {
+        exit_with(AocError::Usage(format!(
+            "Invalid number of args\n{}",
+            usage
+        )));
+    }
+
+    let input_path = positional.first().map(String::as_str);
+
+    let max_removals = match positional.get(1).map(|value| value.parse::<usize>()) {
+        None => DEFAULT_MAX_REMOVALS,
+        Some(Ok(max_removals)) => max_removals,
+        Some(Err(_)) => exit_with(AocError::Usage(format!(
+            "max_removals must be a non-negative number, got '{}'\n{}",
+            positional[1], usage
+        ))),
+    };
+
+    let input = read_input_or_exit(input_path);
+
+    let reports = match parse_reports(&input) {
+        Ok(reports) => reports,
+        Err(error) => exit_with(error.into()),
+    };
+
+    match explain.as_deref() {
+        Some("text") => {
+            for (index, report) in reports.iter().enumerate() {
+                let explanation = explain_report(report, max_removals, &rules);
+                println!("{}", explanation.to_text(index + 1, report));
+            }
+        }
+        Some(_) => {
+            let explained: Vec<Json> = reports
+                .iter()
+                .enumerate()
+                .map(|(index, report)| {
+                    explain_report(report, max_removals, &rules).to_json(index + 1, report)
+                })
+                .collect();
+            println!(
+                "{}",
+                Json::object()
+                    .with("max_removals", max_removals)
+                    .with("reports", explained)
+            );
+        }
+        None => {
+            let mut num_success: i32 = 0;
+
+            for report in reports {
+                if is_report_safe_with_removals(&report, max_removals, &rules) {
+                    num_success += 1;
+                }
+            }
+
+            println!("{}!", num_success);
+        }
+    }
+}
@@ -0,0 +1,222 @@
+// The Problem Dampener generalised to tolerate removing up to `max_removals`
+// levels. For each direction, `removals[i]` is the fewest levels that have to be
+// dropped before index `i` so that the kept levels up to and including `i` are
+// safe. Level `i` can follow the kept level `j` when the step between them is
+// valid, at the cost of dropping everything in between, so only the last
+// `max_removals + 1` candidates for `j` ever need to be looked at.
+
+use crate::SafetyRules;
+
+pub fn is_report_safe_with_removals(
+    report: &[i32],
+    max_removals: usize,
+    rules: &SafetyRules,
+) -> bool {
+    // dropping all but one level always leaves a safe report
+    if report.len() <= max_removals + 1 {
+        return true;
+    }
+
+    levels_to_remove(report, max_removals, rules).is_some()
+}
+
+// the indices of the fewest levels whose removal makes the report safe, or `None`
+// if that needs more than `max_removals` of them
+pub fn levels_to_remove(
+    report: &[i32],
+    max_removals: usize,
+    rules: &SafetyRules,
+) -> Option<Vec<usize>> {
+    rules
+        .directions()
+        .iter()
+        .filter_map(|&should_increase| {
+            fewest_removals(report, should_increase, max_removals, rules)
+        })
+        .min_by_key(Vec::len)
+}
+
+fn fewest_removals(
+    report: &[i32],
+    should_increase: bool,
+    max_removals: usize,
+    rules: &SafetyRules,
+) -> Option<Vec<usize>> {
+    let mut removals: Vec<usize> = Vec::with_capacity(report.len());
+    // the kept level before each one, to recover which levels were dropped
+    let mut previous: Vec<Option<usize>> = Vec::with_capacity(report.len());
+
+    for i in 0..report.len() {
+        // keeping `i` as the first level means dropping everything before it
+        let (mut best, mut best_previous) = (i, None);
+        for j in i.saturating_sub(max_removals + 1)..i {
+            if rules.allows_step(should_increase, report[j], report[i])
+                && removals[j] + (i - j - 1) < best
+            {
+                best = removals[j] + (i - j - 1);
+                best_previous = Some(j);
+            }
+        }
+        removals.push(best);
+        previous.push(best_previous);
+    }
+
+    // the report may also end early by dropping its last few levels
+    let (last, removed) = removals
+        .iter()
+        .enumerate()
+        .map(|(i, removed)| (i, removed + (report.len() - 1 - i)))
+        .min_by_key(|(_, removed)| *removed)?;
+    if removed > max_removals {
+        return None;
+    }
+
+    let mut kept = vec![false; report.len()];
+    let mut current = Some(last);
+    while let Some(index) = current {
+        kept[index] = true;
+        current = previous[index];
+    }
+
+    Some((0..report.len()).filter(|index| !kept[*index]).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{first_violation, is_level_valid, Direction};
+    use aoc_core::testing::Lcg;
+
+    // tries every way of removing up to `max_removals` levels
+    fn brute_force(report: &[i32], max_removals: usize, rules: &SafetyRules) -> bool {
+        if is_level_valid(report, rules) {
+            return true;
+        }
+        if max_removals == 0 {
+            return false;
+        }
+        (0..report.len()).any(|index| {
+            let mut shorter = report.to_vec();
+            shorter.remove(index);
+            brute_force(&shorter, max_removals - 1, rules)
+        })
+    }
+
+    // a small deterministic generator so the cross-check covers many odd reports
+    fn pseudo_random_reports(count: usize) -> Vec<Vec<i32>> {
+        let mut rng = Lcg::new(0x2024_1202);
+
+        (0..count)
+            .map(|_| {
+                let len = rng.below(8) as usize + 1;
+                let mut level = rng.below(10) as i32;
+                (0..len)
+                    .map(|_| {
+                        level += rng.below(11) as i32 - 5;
+                        level
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn matches_the_puzzle_rules_for_zero_and_one_removal() {
+        let rules = SafetyRules::default();
+        let reports = [
+            vec![7, 6, 4, 2, 1],
+            vec![1, 2, 7, 8, 9],
+            vec![9, 7, 6, 2, 1],
+            vec![1, 3, 2, 4, 5],
+            vec![8, 6, 4, 4, 1],
+            vec![1, 3, 6, 7, 9],
+        ];
+        let safe: Vec<bool> = reports
+            .iter()
+            .map(|report| is_report_safe_with_removals(report, 0, &rules))
+            .collect();
+        assert_eq!(safe, vec![true, false, false, false, false, true]);
+
+        let dampened: Vec<bool> = reports
+            .iter()
+            .map(|report| is_report_safe_with_removals(report, 1, &rules))
+            .collect();
+        assert_eq!(dampened, vec![true, false, false, true, true, true]);
+    }
+
+    #[test]
+    fn removes_the_first_level_when_it_sets_the_wrong_direction() {
+        let rules = SafetyRules::default();
+        assert!(is_report_safe_with_removals(&[5, 1, 2, 3, 4], 1, &rules));
+        assert!(is_report_safe_with_removals(&[1, 5, 2, 3, 4], 1, &rules));
+        assert!(!is_report_safe_with_removals(&[5, 9, 1, 2, 3], 1, &rules));
+        assert!(is_report_safe_with_removals(&[5, 9, 1, 2, 3], 2, &rules));
+    }
+
+    #[test]
+    fn names_the_levels_it_removes() {
+        let rules = SafetyRules::default();
+
+        assert_eq!(levels_to_remove(&[7, 6, 4, 2, 1], 1, &rules), Some(vec![]));
+        assert_eq!(levels_to_remove(&[1, 5, 2, 3, 4], 1, &rules), Some(vec![1]));
+        assert_eq!(levels_to_remove(&[9, 6, 4, 5, 1], 1, &rules), Some(vec![3]));
+        assert_eq!(
+            levels_to_remove(&[5, 9, 1, 2, 3], 2, &rules),
+            Some(vec![0, 1])
+        );
+        assert_eq!(levels_to_remove(&[1, 2, 7, 8, 9], 1, &rules), None);
+    }
+
+    #[test]
+    fn agrees_with_removing_and_retesting() {
+        let rule_sets = [
+            SafetyRules::default(),
+            SafetyRules {
+                min_step: 2,
+                max_step: 4,
+                allow_plateaus: true,
+                direction: Direction::Either,
+            },
+            SafetyRules {
+                direction: Direction::Increasing,
+                ..SafetyRules::default()
+            },
+            SafetyRules {
+                allow_plateaus: true,
+                direction: Direction::Decreasing,
+                ..SafetyRules::default()
+            },
+        ];
+
+        for report in pseudo_random_reports(2000) {
+            for rules in &rule_sets {
+                for max_removals in 0..=3 {
+                    if let Some(removed) = levels_to_remove(&report, max_removals, rules) {
+                        let kept: Vec<i32> = (0..report.len())
+                            .filter(|index| !removed.contains(index))
+                            .map(|index| report[index])
+                            .collect();
+                        assert!(
+                            is_level_valid(&kept, rules),
+                            "{:?} minus {:?}",
+                            report,
+                            removed
+                        );
+                    }
+                    assert_eq!(
+                        first_violation(&report, rules).is_none(),
+                        is_level_valid(&report, rules)
+                    );
+                    assert_eq!(
+                        is_report_safe_with_removals(&report, max_removals, rules),
+                        brute_force(&report, max_removals, rules),
+                        "{:?} with up to {} removals under {:?}",
+                        report,
+                        max_removals,
+                        rules
+                    );
+                }
+            }
+        }
+    }
+}
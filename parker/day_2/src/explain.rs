@@ -0,0 +1,241 @@
+// Explains a report's verdict: the first pair of neighbouring levels that breaks
+// the rules, which rule it broke and, when the Problem Dampener saved the
+// report, which levels it removed.
+
+use crate::{levels_to_remove, SafetyRules};
+use aoc_core::Json;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    // the step goes against the required direction, or the one set by the first step
+    DirectionChange,
+    ZeroStep,
+    StepTooSmall,
+    StepTooLarge,
+}
+
+impl Rule {
+    pub fn name(self) -> &'static str {
+        match self {
+            Rule::DirectionChange => "direction change",
+            Rule::ZeroStep => "zero step",
+            Rule::StepTooSmall => "step too small",
+            Rule::StepTooLarge => "step too large",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Safe,
+    DampenedSafe,
+    Unsafe,
+}
+
+impl Verdict {
+    pub fn name(self) -> &'static str {
+        match self {
+            Verdict::Safe => "safe",
+            Verdict::DampenedSafe => "dampened-safe",
+            Verdict::Unsafe => "unsafe",
+        }
+    }
+}
+
+// `first` and `second` are neighbouring indices into the report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Violation {
+    pub first: usize,
+    pub second: usize,
+    pub rule: Rule,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub verdict: Verdict,
+    pub violation: Option<Violation>,
+    // indices of the levels the dampener removed, empty unless dampened-safe
+    pub removed: Vec<usize>,
+}
+
+pub fn explain_report(report: &[i32], max_removals: usize, rules: &SafetyRules) -> Explanation {
+    let violation = first_violation(report, rules);
+    let removed = match violation {
+        None => None,
+        Some(_) => levels_to_remove(report, max_removals, rules),
+    };
+
+    let verdict = match (&violation, &removed) {
+        (None, _) => Verdict::Safe,
+        (Some(_), Some(_)) => Verdict::DampenedSafe,
+        (Some(_), None) => Verdict::Unsafe,
+    };
+
+    Explanation {
+        verdict,
+        violation,
+        removed: removed.unwrap_or_default(),
+    }
+}
+
+pub fn first_violation(report: &[i32], rules: &SafetyRules) -> Option<Violation> {
+    // with either direction allowed, the first step that moves at all picks it
+    let mut should_increase = match rules.directions() {
+        [direction] => Some(*direction),
+        _ => None,
+    };
+
+    for (first, pair) in report.windows(2).enumerate() {
+        let (prev, cur) = (pair[0], pair[1]);
+        let violation = |rule| {
+            Some(Violation {
+                first,
+                second: first + 1,
+                rule,
+            })
+        };
+
+        if prev == cur {
+            if rules.allow_plateaus {
+                continue;
+            }
+            return violation(Rule::ZeroStep);
+        }
+
+        let is_increasing = prev < cur;
+        if *should_increase.get_or_insert(is_increasing) != is_increasing {
+            return violation(Rule::DirectionChange);
+        }
+
+        let step = prev.abs_diff(cur);
+        if step < rules.min_step {
+            return violation(Rule::StepTooSmall);
+        }
+        if step > rules.max_step {
+            return violation(Rule::StepTooLarge);
+        }
+    }
+
+    None
+}
+
+impl Explanation {
+    // `number` is the report's 1-based position in the input
+    pub fn to_text(&self, number: usize, report: &[i32]) -> String {
+        let mut text = format!("report {}: {}", number, self.verdict.name());
+
+        if let Some(violation) = &self.violation {
+            text += &format!(
+                ", indices {} and {} ({} -> {}): {}",
+                violation.first,
+                violation.second,
+                report[violation.first],
+                report[violation.second],
+                violation.rule.name()
+            );
+        }
+
+        if !self.removed.is_empty() {
+            let removed: Vec<String> = self
+                .removed
+                .iter()
+                .map(|index| format!("{} ({})", index, report[*index]))
+                .collect();
+            text += &format!(", removed index {}", removed.join(", "));
+        }
+
+        text
+    }
+
+    pub fn to_json(&self, number: usize, report: &[i32]) -> Json {
+        let violation = self.violation.map(|violation| {
+            Json::object()
+                .with("indices", vec![violation.first, violation.second])
+                .with("rule", violation.rule.name())
+        });
+
+        Json::object()
+            .with("report", number)
+            .with("levels", report.to_vec())
+            .with("verdict", self.verdict.name())
+            .with("violation", violation)
+            .with("removed", self.removed.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Direction;
+
+    #[test]
+    fn explains_the_example_reports() {
+        let rules = SafetyRules::default();
+        let explain = |report: &[i32]| explain_report(report, 1, &rules);
+
+        assert_eq!(explain(&[7, 6, 4, 2, 1]).verdict, Verdict::Safe);
+        assert_eq!(explain(&[7, 6, 4, 2, 1]).violation, None);
+
+        let too_large = explain(&[1, 2, 7, 8, 9]);
+        assert_eq!(too_large.verdict, Verdict::Unsafe);
+        assert_eq!(
+            too_large.violation,
+            Some(Violation {
+                first: 1,
+                second: 2,
+                rule: Rule::StepTooLarge
+            })
+        );
+        assert!(too_large.removed.is_empty());
+
+        let turned = explain(&[1, 3, 2, 4, 5]);
+        assert_eq!(turned.verdict, Verdict::DampenedSafe);
+        assert_eq!(turned.violation.unwrap().rule, Rule::DirectionChange);
+        assert_eq!(turned.removed.len(), 1);
+
+        let flat = explain(&[8, 6, 4, 4, 1]);
+        assert_eq!(
+            flat.violation,
+            Some(Violation {
+                first: 2,
+                second: 3,
+                rule: Rule::ZeroStep
+            })
+        );
+    }
+
+    #[test]
+    fn checks_against_the_required_direction_and_step_range() {
+        let rules = SafetyRules {
+            min_step: 2,
+            max_step: 3,
+            allow_plateaus: true,
+            direction: Direction::Increasing,
+        };
+
+        assert_eq!(first_violation(&[1, 1, 3, 5], &rules), None);
+        assert_eq!(
+            first_violation(&[5, 3, 5], &rules).map(|violation| violation.rule),
+            Some(Rule::DirectionChange)
+        );
+        assert_eq!(
+            first_violation(&[1, 3, 4], &rules).map(|violation| violation.rule),
+            Some(Rule::StepTooSmall)
+        );
+    }
+
+    #[test]
+    fn renders_text_and_json() {
+        let report = [1, 5, 2, 3, 4];
+        let explanation = explain_report(&report, 1, &SafetyRules::default());
+
+        assert_eq!(
+            explanation.to_text(4, &report),
+            "report 4: dampened-safe, indices 0 and 1 (1 -> 5): step too large, removed index 1 (5)"
+        );
+        assert_eq!(
+            explanation.to_json(4, &report).to_string(),
+            r#"{"report":4,"levels":[1,5,2,3,4],"verdict":"dampened-safe","violation":{"indices":[0,1],"rule":"step too large"},"removed":[1]}"#
+        );
+    }
+}
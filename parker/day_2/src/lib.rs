@@ -0,0 +1,53 @@
+use aoc_core::{Answer, AocError, Solution};
+use input_read_util::{parse_int_rows, ParseError, Separator};
+
+mod dampener;
+mod explain;
+mod rules;
+
+pub use dampener::{is_report_safe_with_removals, levels_to_remove};
+pub use explain::{explain_report, first_violation, Explanation, Rule, Verdict, Violation};
+pub use rules::{Direction, SafetyRules, RULE_FLAGS};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        Ok(parse_reports(input)?)
+    }
+
+    fn part1(reports: &Self::Input) -> Answer {
+        reports
+            .iter()
+            .filter(|level| is_level_valid(level, &SafetyRules::default()))
+            .count()
+            .into()
+    }
+
+    fn part2(reports: &Self::Input) -> Answer {
+        reports
+            .iter()
+            .filter(|report| is_report_safe(report, &SafetyRules::default()))
+            .count()
+            .into()
+    }
+}
+
+pub fn parse_reports(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    parse_int_rows(input, Separator::Whitespace)
+}
+
+pub fn is_level_valid(level: &[i32], rules: &SafetyRules) -> bool {
+    rules.directions().iter().any(|&should_increase| {
+        level
+            .windows(2)
+            .all(|pair| rules.allows_step(should_increase, pair[0], pair[1]))
+    })
+}
+
+// part 2: safe once at most one level is removed
+pub fn is_report_safe(report: &[i32], rules: &SafetyRules) -> bool {
+    is_report_safe_with_removals(report, 1, rules)
+}
@@ -0,0 +1,192 @@
+// What makes a report safe. The puzzle's rules are the default: levels must
+// all increase or all decrease, by at least 1 and at most 3 at each step.
+
+use aoc_core::AocError;
+use std::str::FromStr;
+
+pub const RULE_FLAGS: &str =
+    "[--min-step <n>] [--max-step <n>] [--allow-plateaus] [--direction increasing|decreasing|either]";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Increasing,
+    Decreasing,
+    Either,
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "increasing" => Ok(Direction::Increasing),
+            "decreasing" => Ok(Direction::Decreasing),
+            "either" => Ok(Direction::Either),
+            _ => Err(format!(
+                "--direction expects increasing, decreasing or either, got '{}'",
+                name
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SafetyRules {
+    pub min_step: u32,
+    pub max_step: u32,
+    // whether two equal neighbouring levels are allowed, they never decide the direction
+    pub allow_plateaus: bool,
+    pub direction: Direction,
+}
+
+impl Default for SafetyRules {
+    fn default() -> Self {
+        SafetyRules {
+            min_step: 1,
+            max_step: 3,
+            allow_plateaus: false,
+            direction: Direction::Either,
+        }
+    }
+}
+
+impl SafetyRules {
+    // the directions a whole report may take, `true` meaning increasing
+    pub fn directions(&self) -> &'static [bool] {
+        match self.direction {
+            Direction::Increasing => &[true],
+            Direction::Decreasing => &[false],
+            Direction::Either => &[true, false],
+        }
+    }
+
+    // whether `prev` can be followed by `cur` in a report heading in the given direction
+    pub fn allows_step(&self, should_increase: bool, prev: i32, cur: i32) -> bool {
+        if prev == cur {
+            return self.allow_plateaus;
+        }
+        if (prev < cur) != should_increase {
+            return false;
+        }
+
+        let step = prev.abs_diff(cur);
+        self.min_step <= step && step <= self.max_step
+    }
+
+    // pulls the rule flags out of `args`, handing back the remaining positional arguments
+    pub fn from_args(args: &[String]) -> Result<(Self, Vec<String>), AocError> {
+        let mut rules = SafetyRules::default();
+        let mut positional = Vec::new();
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--allow-plateaus" => rules.allow_plateaus = true,
+                "--min-step" | "--max-step" | "--direction" => {
+                    let Some(value) = args.next() else {
+                        return Err(AocError::Usage(format!("missing value for {}", arg)));
+                    };
+                    match arg.as_str() {
+                        "--min-step" => rules.min_step = step_flag(arg, value)?,
+                        "--max-step" => rules.max_step = step_flag(arg, value)?,
+                        _ => rules.direction = value.parse().map_err(AocError::Usage)?,
+                    }
+                }
+                // a lone `-` is the stdin path, not a flag
+                flag if flag.starts_with("--") => {
+                    return Err(AocError::Usage(format!("unknown flag '{}'", flag)));
+                }
+                _ => positional.push(arg.clone()),
+            }
+        }
+
+        rules.validate()?;
+        Ok((rules, positional))
+    }
+
+    fn validate(&self) -> Result<(), AocError> {
+        if self.min_step == 0 {
+            return Err(AocError::Usage(String::from(
+                "--min-step must be at least 1, use --allow-plateaus to permit equal levels",
+            )));
+        }
+        if self.min_step > self.max_step {
+            return Err(AocError::Usage(format!(
+                "--min-step {} is larger than --max-step {}",
+                self.min_step, self.max_step
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn step_flag(flag: &str, value: &str) -> Result<u32, AocError> {
+    value.parse::<u32>().map_err(|_| {
+        AocError::Usage(format!(
+            "{} expects a non-negative number, got '{}'",
+            flag, value
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn reads_rules_from_flags_and_keeps_positionals() {
+        let (rules, positional) = SafetyRules::from_args(&args(&[
+            "--max-step",
+            "5",
+            "input.txt",
+            "--allow-plateaus",
+            "--direction",
+            "decreasing",
+            "2",
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            rules,
+            SafetyRules {
+                min_step: 1,
+                max_step: 5,
+                allow_plateaus: true,
+                direction: Direction::Decreasing,
+            }
+        );
+        assert_eq!(positional, args(&["input.txt", "2"]));
+    }
+
+    #[test]
+    fn rejects_unusable_flags() {
+        for bad in [
+            &["--min-step"][..],
+            &["--min-step", "0"],
+            &["--min-step", "4", "--max-step", "2"],
+            &["--max-step", "-1"],
+            &["--direction", "sideways"],
+            &["--plateaus"],
+        ] {
+            assert!(SafetyRules::from_args(&args(bad)).is_err(), "{:?}", bad);
+        }
+    }
+
+    #[test]
+    fn plateaus_never_break_a_report_that_allows_them() {
+        let rules = SafetyRules {
+            allow_plateaus: true,
+            ..SafetyRules::default()
+        };
+
+        assert!(rules.allows_step(true, 4, 4));
+        assert!(rules.allows_step(false, 4, 4));
+        assert!(!SafetyRules::default().allows_step(true, 4, 4));
+        assert!(!rules.allows_step(true, 4, 8));
+        assert!(!rules.allows_step(true, 4, 3));
+    }
+}
@@ -0,0 +1,10 @@
+use parker_day_3::{run_cli, Interpreter};
+use std::env;
+
+fn main() {
+    run_cli(
+        &env::args().skip(1).collect::<Vec<_>>(),
+        &Interpreter::PART2,
+        "problem2",
+    );
+}
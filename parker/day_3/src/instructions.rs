@@ -0,0 +1,142 @@
+// The instructions the lexer recognises and what each one does. An instruction
+// is a name followed by a parenthesised, comma-separated list of 1-3 digit
+// operands, e.g. `mul(2,4)` or `reset()`. New ones are registered on an
+// `InstructionSet` with the number of operands they take and their semantics.
+
+// every arity has a maximum, which bounds how far the stream lexer looks ahead
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Arity {
+    Exactly(usize),
+    // inclusive on both ends
+    Between(usize, usize),
+}
+
+impl Arity {
+    pub fn accepts(self, count: usize) -> bool {
+        match self {
+            Arity::Exactly(expected) => count == expected,
+            Arity::Between(minimum, maximum) => (minimum..=maximum).contains(&count),
+        }
+    }
+
+    pub fn max(self) -> usize {
+        match self {
+            Arity::Exactly(expected) => expected,
+            Arity::Between(_, maximum) => maximum,
+        }
+    }
+}
+
+// what executing an instruction does to the machine
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Effect {
+    Accumulate(i64),
+    Enable,
+    Disable,
+    Reset,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Instruction {
+    pub name: &'static str,
+    pub arity: Arity,
+    pub semantics: fn(&[u32]) -> Effect,
+}
+
+#[derive(Debug, Clone)]
+pub struct InstructionSet {
+    pub name: &'static str,
+    pub instructions: Vec<Instruction>,
+}
+
+impl InstructionSet {
+    pub fn new(name: &'static str) -> Self {
+        InstructionSet {
+            name,
+            instructions: Vec::new(),
+        }
+    }
+
+    pub fn register(
+        mut self,
+        name: &'static str,
+        arity: Arity,
+        semantics: fn(&[u32]) -> Effect,
+    ) -> Self {
+        self.instructions.push(Instruction {
+            name,
+            arity,
+            semantics,
+        });
+        self
+    }
+
+    // the puzzle's language: two operand `mul` switched on and off by `do()` and `don't()`
+    pub fn puzzle() -> Self {
+        InstructionSet::new("puzzle")
+            .register("mul", Arity::Exactly(2), product)
+            .register("do", Arity::Exactly(0), |_| Effect::Enable)
+            .register("don't", Arity::Exactly(0), |_| Effect::Disable)
+    }
+
+    pub fn arithmetic() -> Self {
+        InstructionSet::new("arithmetic")
+            .register("add", Arity::Exactly(2), |operands| {
+                Effect::Accumulate(i64::from(operands[0]) + i64::from(operands[1]))
+            })
+            .register("sub", Arity::Exactly(2), |operands| {
+                Effect::Accumulate(i64::from(operands[0]) - i64::from(operands[1]))
+            })
+            // six 3 digit operands keep the product within an i64, the running sum of
+            // those products is kept in an i128 by the interpreter
+            .register("mul", Arity::Between(1, 6), product)
+            .register("reset", Arity::Exactly(0), |_| Effect::Reset)
+            .register("do", Arity::Exactly(0), |_| Effect::Enable)
+            .register("don't", Arity::Exactly(0), |_| Effect::Disable)
+    }
+
+    pub fn find(&self, name: &str) -> Option<&Instruction> {
+        self.instructions
+            .iter()
+            .find(|instruction| instruction.name == name)
+    }
+}
+
+pub const INSTRUCTION_SETS: [&str; 2] = ["puzzle", "arithmetic"];
+
+pub fn instruction_set(name: &str) -> Option<InstructionSet> {
+    match name {
+        "puzzle" => Some(InstructionSet::puzzle()),
+        "arithmetic" => Some(InstructionSet::arithmetic()),
+        _ => None,
+    }
+}
+
+fn product(operands: &[u32]) -> Effect {
+    Effect::Accumulate(operands.iter().map(|operand| i64::from(*operand)).product())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registers_instructions_with_their_shape() {
+        let set = InstructionSet::arithmetic();
+
+        let mul = set.find("mul").unwrap();
+        assert!(mul.arity.accepts(3));
+        assert!(!mul.arity.accepts(0));
+        assert!(!mul.arity.accepts(7));
+        assert_eq!((mul.semantics)(&[2, 3, 4]), Effect::Accumulate(24));
+        assert_eq!(
+            (set.find("sub").unwrap().semantics)(&[2, 5]),
+            Effect::Accumulate(-3)
+        );
+        assert!(InstructionSet::puzzle().find("add").is_none());
+
+        for name in INSTRUCTION_SETS {
+            assert_eq!(instruction_set(name).unwrap().name, name);
+        }
+    }
+}
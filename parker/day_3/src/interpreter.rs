@@ -0,0 +1,98 @@
+// Executes a token stream. Both parts run the same interpreter: part 1 ignores
+// `do()`/`don't()`, part 2 lets them switch the other instructions on and off.
+
+use crate::{Effect, Token};
+use std::borrow::Borrow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Interpreter {
+    pub honour_conditionals: bool,
+}
+
+impl Interpreter {
+    pub const PART1: Interpreter = Interpreter {
+        honour_conditionals: false,
+    };
+    pub const PART2: Interpreter = Interpreter {
+        honour_conditionals: true,
+    };
+
+    pub fn run<T: Borrow<Token>>(&self, tokens: impl IntoIterator<Item = T>) -> i128 {
+        let mut machine = Machine::new();
+        for token in tokens {
+            self.step(&mut machine, token.borrow());
+        }
+        machine.sum
+    }
+
+    // executes one instruction, handing back its effect whether or not it was applied
+    pub fn step(&self, machine: &mut Machine, token: &Token) -> Effect {
+        let effect = (token.instruction.semantics)(&token.operands);
+        match effect {
+            Effect::Enable => machine.enabled = true,
+            Effect::Disable => machine.enabled = !self.honour_conditionals,
+            Effect::Accumulate(value) if machine.enabled => machine.sum += i128::from(value),
+            Effect::Reset if machine.enabled => machine.sum = 0,
+            Effect::Accumulate(_) | Effect::Reset => {}
+        }
+        effect
+    }
+}
+
+// the state carried from one instruction to the next
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Machine {
+    pub enabled: bool,
+    pub sum: i128,
+}
+
+impl Machine {
+    pub fn new() -> Self {
+        Machine {
+            enabled: true,
+            sum: 0,
+        }
+    }
+}
+
+impl Default for Machine {
+    fn default() -> Self {
+        Machine::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tokenize, InstructionSet};
+
+    #[test]
+    fn runs_both_parts_over_the_same_tokens() {
+        let tokens = tokenize(
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
+            &InstructionSet::puzzle(),
+        );
+
+        assert_eq!(Interpreter::PART1.run(&tokens), 161);
+        assert_eq!(Interpreter::PART2.run(tokens), 48);
+    }
+
+    #[test]
+    fn runs_the_arithmetic_instructions() {
+        let tokens = tokenize(
+            "add(2,3)mul(2,3,4)reset()sub(1,8)don't()add(9,9)reset()do()mul(5)",
+            &InstructionSet::arithmetic(),
+        );
+
+        assert_eq!(Interpreter::PART2.run(&tokens), -2);
+        assert_eq!(Interpreter::PART1.run(&tokens), 5);
+    }
+
+    #[test]
+    fn sums_past_the_range_of_an_i64() {
+        let input = "mul(999,999,999,999,999,999)".repeat(20);
+        let tokens = tokenize(&input, &InstructionSet::arithmetic());
+
+        assert_eq!(Interpreter::PART1.run(&tokens), 20 * 999_i128.pow(6));
+    }
+}
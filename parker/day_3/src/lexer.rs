@@ -0,0 +1,201 @@
+// A hand-written lexer for the corrupted memory. Anything that is not exactly
+// one of the set's instructions is noise and skipped a byte at a time, so
+// `mul(1,2` or `mul ( 1,2)` produce nothing while the `do()` inside `undo()`
+// still counts.
+
+use crate::{Instruction, InstructionSet};
+
+// the puzzle's operands are 1 to 3 digit numbers
+pub const MAX_OPERAND_DIGITS: usize = 3;
+
+// a half-open byte range into the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct Token {
+    pub instruction: Instruction,
+    pub operands: Vec<u32>,
+    pub span: Span,
+}
+
+pub struct Lexer<'a> {
+    input: &'a [u8],
+    position: usize,
+    instructions: &'a InstructionSet,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(input: &'a str, instructions: &'a InstructionSet) -> Self {
+        Lexer {
+            input: input.as_bytes(),
+            position: 0,
+            instructions,
+        }
+    }
+}
+
+impl Iterator for Lexer<'_> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        while self.position < self.input.len() {
+            let start = self.position;
+            match lex_instruction(&self.input[start..], self.instructions) {
+                Some((instruction, operands, len)) => {
+                    self.position += len;
+                    return Some(Token {
+                        instruction,
+                        operands,
+                        span: Span {
+                            start,
+                            end: start + len,
+                        },
+                    });
+                }
+                None => self.position += 1,
+            }
+        }
+        None
+    }
+}
+
+pub fn tokenize(input: &str, instructions: &InstructionSet) -> Vec<Token> {
+    Lexer::new(input, instructions).collect()
+}
+
+// the first of the set's instructions found at the very start of `bytes`, with
+// its operands and how many bytes it takes up
+pub(crate) fn lex_instruction(
+    bytes: &[u8],
+    instructions: &InstructionSet,
+) -> Option<(Instruction, Vec<u32>, usize)> {
+    instructions.instructions.iter().find_map(|instruction| {
+        let rest = bytes.strip_prefix(instruction.name.as_bytes())?;
+        let (operands, len) = lex_operands(rest, instruction.arity.max())?;
+        if !instruction.arity.accepts(operands.len()) {
+            return None;
+        }
+        Some((*instruction, operands, instruction.name.len() + len))
+    })
+}
+
+// a parenthesised list of at most `max_operands` operands, such as `(2,4)` or `()`
+fn lex_operands(bytes: &[u8], max_operands: usize) -> Option<(Vec<u32>, usize)> {
+    let mut position = 1;
+    bytes.strip_prefix(b"(")?;
+
+    let mut operands = Vec::new();
+    if bytes.get(position) == Some(&b')') {
+        return Some((operands, position + 1));
+    }
+
+    loop {
+        let (operand, len) = lex_operand(&bytes[position..])?;
+        operands.push(operand);
+        position += len;
+
+        match bytes.get(position)? {
+            b',' if operands.len() < max_operands => position += 1,
+            b')' => return Some((operands, position + 1)),
+            _ => return None,
+        }
+    }
+}
+
+// a fourth digit is left in place, where it fails the `,` or `)` that has to follow
+fn lex_operand(bytes: &[u8]) -> Option<(u32, usize)> {
+    let len = bytes
+        .iter()
+        .take(MAX_OPERAND_DIGITS)
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    if len == 0 {
+        return None;
+    }
+
+    let value = bytes[..len]
+        .iter()
+        .fold(0, |value, digit| value * 10 + u32::from(digit - b'0'));
+    Some((value, len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    fn lexed(input: &str, instructions: &InstructionSet) -> Vec<(&'static str, Vec<u32>)> {
+        tokenize(input, instructions)
+            .into_iter()
+            .map(|token| (token.instruction.name, token.operands))
+            .collect()
+    }
+
+    #[test]
+    fn tokenizes_the_example_with_spans() {
+        let tokens = tokenize(EXAMPLE, &InstructionSet::puzzle());
+
+        assert_eq!(
+            lexed(EXAMPLE, &InstructionSet::puzzle()),
+            vec![
+                ("mul", vec![2, 4]),
+                ("don't", vec![]),
+                ("mul", vec![5, 5]),
+                ("mul", vec![11, 8]),
+                ("do", vec![]),
+                ("mul", vec![8, 5]),
+            ]
+        );
+        for token in &tokens {
+            let text = &EXAMPLE[token.span.start..token.span.end];
+            assert!(text.starts_with(token.instruction.name), "{}", text);
+        }
+        assert_eq!(tokens[0].span, Span { start: 1, end: 9 });
+        assert_eq!(&EXAMPLE[tokens[4].span.start..tokens[4].span.end], "do()");
+    }
+
+    #[test]
+    fn limits_operands_to_three_digits() {
+        assert_eq!(
+            lexed(
+                "mul(123,456)mul(1234,5)mul(1,2345)mul(,1)mul(007,1)",
+                &InstructionSet::puzzle()
+            ),
+            vec![("mul", vec![123, 456]), ("mul", vec![7, 1])]
+        );
+    }
+
+    #[test]
+    fn recovers_inside_broken_instructions() {
+        assert_eq!(
+            lexed("mul(1,mul(2,3)don't(do()", &InstructionSet::puzzle()),
+            vec![("mul", vec![2, 3]), ("do", vec![])]
+        );
+    }
+
+    #[test]
+    fn checks_each_instructions_arity() {
+        let input = "mul(2)mul(1,2,3)add(1)add(4,5)reset(1)reset()";
+
+        assert_eq!(
+            lexed(input, &InstructionSet::puzzle()),
+            Vec::<(&str, Vec<u32>)>::new()
+        );
+        assert_eq!(
+            lexed(input, &InstructionSet::arithmetic()),
+            vec![
+                ("mul", vec![2]),
+                ("mul", vec![1, 2, 3]),
+                ("add", vec![4, 5]),
+                ("reset", vec![]),
+            ]
+        );
+        assert!(lexed("mul(1,1,1,1,1,1,1)", &InstructionSet::arithmetic()).is_empty());
+    }
+}
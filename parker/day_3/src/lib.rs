@@ -0,0 +1,36 @@
+use aoc_core::{Answer, AocError, Solution};
+
+mod instructions;
+mod interpreter;
+mod lexer;
+mod options;
+mod stream;
+mod trace;
+
+pub use instructions::{
+    instruction_set, Arity, Effect, Instruction, InstructionSet, INSTRUCTION_SETS,
+};
+pub use interpreter::{Interpreter, Machine};
+pub use lexer::{tokenize, Lexer, Span, Token, MAX_OPERAND_DIGITS};
+pub use options::{run_cli, Options, OPTION_FLAGS};
+pub use stream::{longest_instruction, run_stream, StreamLexer, DEFAULT_CHUNK_SIZE};
+pub use trace::{highlight, trace, trace_header, TraceEntry};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<Token>;
+
+    fn parse(input: &str) -> Result<Self::Input, AocError> {
+        // corrupted memory cannot be malformed, anything that is not an instruction is skipped
+        Ok(tokenize(input, &InstructionSet::puzzle()))
+    }
+
+    fn part1(tokens: &Self::Input) -> Answer {
+        Interpreter::PART1.run(tokens).into()
+    }
+
+    fn part2(tokens: &Self::Input) -> Answer {
+        Interpreter::PART2.run(tokens).into()
+    }
+}
@@ -0,0 +1,112 @@
+// Command line options shared by both day 3 binaries, and the body both of them run.
+
+use crate::{
+    highlight, instruction_set, run_stream, tokenize, trace, trace_header, InstructionSet,
+    Interpreter, INSTRUCTION_SETS,
+};
+use aoc_core::{exit_with, open_input_or_exit, AocError};
+use input_read_util::input_name;
+use std::io::Read;
+
+pub const OPTION_FLAGS: &str = "[--instructions <set>] [--trace] [--highlight]";
+
+pub struct Options {
+    pub instructions: InstructionSet,
+    // print every recognised instruction as it is executed
+    pub trace: bool,
+    // print the input with the recognised instructions coloured in
+    pub highlight: bool,
+    pub input_path: Option<String>,
+}
+
+impl Options {
+    pub fn from_args(args: &[String]) -> Result<Self, AocError> {
+        let mut options = Options {
+            instructions: InstructionSet::puzzle(),
+            trace: false,
+            highlight: false,
+            input_path: None,
+        };
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--trace" => options.trace = true,
+                "--highlight" => options.highlight = true,
+                "--instructions" => {
+                    let Some(name) = args.next() else {
+                        return Err(AocError::Usage(String::from(
+                            "missing value for --instructions",
+                        )));
+                    };
+                    options.instructions = instruction_set(name).ok_or_else(|| {
+                        AocError::Usage(format!(
+                            "unknown instruction set '{}', expected one of {}",
+                            name,
+                            INSTRUCTION_SETS.join(", ")
+                        ))
+                    })?;
+                }
+                // a lone `-` is the stdin path, not a flag
+                flag if flag.starts_with("--") => {
+                    return Err(AocError::Usage(format!("unknown flag '{}'", flag)));
+                }
+                path if options.input_path.is_none() => options.input_path = Some(path.to_string()),
+                _ => return Err(AocError::Usage(String::from("Invalid number of args"))),
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+// parses the options, then prints the answer of `interpreter` along with any trace or highlight
+pub fn run_cli(args: &[String], interpreter: &Interpreter, name: &str) {
+    let usage = format!("{} {} [<path_to_input> | -]", name, OPTION_FLAGS);
+
+    let options = match Options::from_args(args) {
+        Ok(options) => options,
+        Err(AocError::Usage(message)) => {
+            exit_with(AocError::Usage(format!("{}\n{}", message, usage)))
+        }
+        Err(error) => exit_with(error),
+    };
+
+    let input_path = options.input_path.as_deref();
+
+    // tracing needs the whole input to report positions and reprint it, otherwise stream it
+    if !options.trace && !options.highlight {
+        let reader = open_input_or_exit(input_path);
+        match run_stream(reader, &options.instructions, interpreter) {
+            Ok(sum) => println!("{}", sum),
+            Err(error) => exit_with(AocError::io(input_name(input_path), error)),
+        }
+        return;
+    }
+
+    // like the stream lexer, bytes that are not UTF-8 are just more noise in the memory
+    let mut bytes = Vec::new();
+    if let Err(error) = open_input_or_exit(input_path).read_to_end(&mut bytes) {
+        exit_with(AocError::io(input_name(input_path), error));
+    }
+    let input = String::from_utf8_lossy(&bytes);
+
+    let tokens = tokenize(&input, &options.instructions);
+    let entries = trace(&input, &tokens, interpreter);
+    if options.trace {
+        println!("{}", trace_header());
+        for entry in &entries {
+            println!("{}", entry.to_text());
+        }
+    }
+    if options.highlight {
+        let highlighted = highlight(&input, &entries);
+        print!("{}", highlighted);
+        // keeps the answer on its own line when the input has no trailing newline
+        if !highlighted.ends_with('\n') {
+            println!();
+        }
+    }
+
+    println!("{}", interpreter.run(&tokens));
+}
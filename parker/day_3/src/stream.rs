@@ -0,0 +1,218 @@
+// Lexes corrupted memory straight from a reader, so dumps far larger than
+// memory can be scanned. Only a chunk plus the longest possible instruction is
+// ever buffered: a byte is scanned once enough of what follows it has been read
+// to hold any instruction, so an instruction split across reads (or lines
+// printed in between reads) is still found whole.
+
+use crate::lexer::lex_instruction;
+use crate::{InstructionSet, Interpreter, Machine, Span, Token, MAX_OPERAND_DIGITS};
+use std::io::{self, ErrorKind, Read};
+
+pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+pub struct StreamLexer<'a, R> {
+    reader: R,
+    instructions: &'a InstructionSet,
+    buffer: Vec<u8>,
+    // the next byte of `buffer` to scan, and the offset of `buffer[0]` in the whole stream
+    position: usize,
+    offset: usize,
+    chunk_size: usize,
+    lookahead: usize,
+    finished: bool,
+}
+
+impl<'a, R: Read> StreamLexer<'a, R> {
+    pub fn new(reader: R, instructions: &'a InstructionSet) -> Self {
+        StreamLexer::with_chunk_size(reader, instructions, DEFAULT_CHUNK_SIZE)
+    }
+
+    pub fn with_chunk_size(reader: R, instructions: &'a InstructionSet, chunk_size: usize) -> Self {
+        StreamLexer {
+            reader,
+            instructions,
+            buffer: Vec::new(),
+            position: 0,
+            offset: 0,
+            chunk_size: chunk_size.max(1),
+            lookahead: longest_instruction(instructions),
+            finished: false,
+        }
+    }
+
+    // drops what has been scanned and reads the next chunk after what is left
+    fn fill(&mut self) -> io::Result<()> {
+        self.buffer.drain(..self.position);
+        self.offset += self.position;
+        self.position = 0;
+
+        let start = self.buffer.len();
+        self.buffer.resize(start + self.chunk_size, 0);
+        let read = loop {
+            match self.reader.read(&mut self.buffer[start..]) {
+                Ok(read) => break read,
+                Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                Err(error) => {
+                    self.buffer.truncate(start);
+                    return Err(error);
+                }
+            }
+        };
+        self.buffer.truncate(start + read);
+        self.finished = read == 0;
+
+        Ok(())
+    }
+}
+
+impl<R: Read> Iterator for StreamLexer<'_, R> {
+    type Item = io::Result<Token>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let available = self.buffer.len() - self.position;
+            if !self.finished && available < self.lookahead {
+                if let Err(error) = self.fill() {
+                    return Some(Err(error));
+                }
+                continue;
+            }
+            if available == 0 {
+                return None;
+            }
+
+            // without the whole lookahead an instruction could be cut short, so stop before it
+            let limit = if self.finished {
+                self.buffer.len()
+            } else {
+                self.buffer.len() - self.lookahead + 1
+            };
+
+            while self.position < limit {
+                let start = self.position;
+                if let Some((instruction, operands, len)) =
+                    lex_instruction(&self.buffer[start..], self.instructions)
+                {
+                    self.position += len;
+                    return Some(Ok(Token {
+                        instruction,
+                        operands,
+                        span: Span {
+                            start: self.offset + start,
+                            end: self.offset + start + len,
+                        },
+                    }));
+                }
+                self.position += 1;
+            }
+        }
+    }
+}
+
+// how many bytes the longest instruction of the set can take up
+pub fn longest_instruction(instructions: &InstructionSet) -> usize {
+    instructions
+        .instructions
+        .iter()
+        .map(|instruction| {
+            // the `(`, then each operand with the `,` or `)` after it, or just `)` without any
+            let operands = instruction.arity.max().max(1) * (MAX_OPERAND_DIGITS + 1);
+            instruction.name.len() + 1 + operands
+        })
+        .max()
+        .unwrap_or(1)
+}
+
+// runs the interpreter over a reader, keeping only the machine state between instructions
+pub fn run_stream(
+    reader: impl Read,
+    instructions: &InstructionSet,
+    interpreter: &Interpreter,
+) -> io::Result<i128> {
+    let mut machine = Machine::new();
+    for token in StreamLexer::new(reader, instructions) {
+        interpreter.step(&mut machine, &token?);
+    }
+    Ok(machine.sum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tokenize;
+
+    const EXAMPLE: &str =
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+
+    // hands out at most `step` bytes per read, like a pipe delivering a dump in pieces
+    struct Trickle<'a> {
+        bytes: &'a [u8],
+        step: usize,
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+            let len = self.step.min(buffer.len()).min(self.bytes.len());
+            buffer[..len].copy_from_slice(&self.bytes[..len]);
+            self.bytes = &self.bytes[len..];
+            Ok(len)
+        }
+    }
+
+    fn spans(tokens: impl IntoIterator<Item = Token>) -> Vec<(usize, usize, Vec<u32>)> {
+        tokens
+            .into_iter()
+            .map(|token| (token.span.start, token.span.end, token.operands))
+            .collect()
+    }
+
+    #[test]
+    fn finds_instructions_split_across_reads() {
+        let input = format!("{}\n{}mul(1,2,3)reset()", EXAMPLE, EXAMPLE);
+
+        for instructions in [InstructionSet::puzzle(), InstructionSet::arithmetic()] {
+            let expected = spans(tokenize(&input, &instructions));
+            for chunk_size in 1..=40 {
+                for step in [1, 3, 7, 64] {
+                    let reader = Trickle {
+                        bytes: input.as_bytes(),
+                        step,
+                    };
+                    let streamed: io::Result<Vec<Token>> =
+                        StreamLexer::with_chunk_size(reader, &instructions, chunk_size).collect();
+                    assert_eq!(
+                        spans(streamed.unwrap()),
+                        expected,
+                        "chunks of {} read {} bytes at a time",
+                        chunk_size,
+                        step
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_the_enable_flag_across_lines_and_chunks() {
+        let input = "mul(2,4)don't()\nmul(5,5)\nmul(11,8)do()\nmul(8,5)";
+        let reader = Trickle {
+            bytes: input.as_bytes(),
+            step: 5,
+        };
+
+        assert_eq!(
+            run_stream(reader, &InstructionSet::puzzle(), &Interpreter::PART2).unwrap(),
+            48
+        );
+    }
+
+    #[test]
+    fn skips_bytes_that_are_not_utf8() {
+        let bytes = b"\xffmul(3,\xfe4)mul(6,7)\xc3";
+
+        assert_eq!(
+            run_stream(&bytes[..], &InstructionSet::puzzle(), &Interpreter::PART1).unwrap(),
+            42
+        );
+    }
+}
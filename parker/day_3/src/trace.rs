@@ -0,0 +1,181 @@
+// Records what the interpreter did with every recognised instruction, so a
+// wrong answer can be traced back to the instructions that were (or were not)
+// counted.
+
+use crate::{Effect, Interpreter, Machine, Span, Token};
+
+const GREEN: &str = "\x1b[1;32m";
+const RED: &str = "\x1b[2;31m";
+const RESET: &str = "\x1b[0m";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceEntry {
+    pub span: Span,
+    // 1-based, the column counts chars rather than bytes
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    // whether instructions were switched on when this one ran
+    pub enabled: bool,
+    // what the instruction computed, counted or not
+    pub value: Option<i64>,
+    pub sum: i128,
+}
+
+pub fn trace(input: &str, tokens: &[Token], interpreter: &Interpreter) -> Vec<TraceEntry> {
+    let mut machine = Machine::new();
+    let mut lines = LineTracker::new(input);
+
+    tokens
+        .iter()
+        .map(|token| {
+            let enabled = machine.enabled;
+            let value = match interpreter.step(&mut machine, token) {
+                Effect::Accumulate(value) => Some(value),
+                _ => None,
+            };
+            let (line, column) = lines.locate(token.span.start);
+
+            TraceEntry {
+                span: token.span,
+                line,
+                column,
+                text: input[token.span.start..token.span.end].to_string(),
+                enabled,
+                value,
+                sum: machine.sum,
+            }
+        })
+        .collect()
+}
+
+impl TraceEntry {
+    pub fn to_text(&self) -> String {
+        let value = match self.value {
+            Some(value) => value.to_string(),
+            None => String::from("-"),
+        };
+        trace_row(
+            &format!("{}:{}", self.line, self.column),
+            &self.text,
+            if self.enabled { "enabled" } else { "disabled" },
+            &value,
+            &self.sum.to_string(),
+        )
+    }
+}
+
+pub fn trace_header() -> String {
+    trace_row("line:col", "instruction", "state", "product", "running sum")
+}
+
+fn trace_row(position: &str, text: &str, state: &str, value: &str, sum: &str) -> String {
+    format!(
+        "{:>10}  {:<20} {:<8} {:>12} {:>14}",
+        position, text, state, value, sum
+    )
+}
+
+// the input with instructions that ran in green and disabled ones dimmed in red
+pub fn highlight(input: &str, entries: &[TraceEntry]) -> String {
+    let mut highlighted = String::with_capacity(input.len());
+    let mut position = 0;
+
+    for entry in entries {
+        highlighted += &input[position..entry.span.start];
+        highlighted += if entry.enabled { GREEN } else { RED };
+        highlighted += &entry.text;
+        highlighted += RESET;
+        position = entry.span.end;
+    }
+    highlighted += &input[position..];
+
+    highlighted
+}
+
+// turns increasing byte offsets into line and column numbers without rescanning the input
+struct LineTracker<'a> {
+    input: &'a str,
+    offset: usize,
+    line: usize,
+    line_start: usize,
+}
+
+impl<'a> LineTracker<'a> {
+    fn new(input: &'a str) -> Self {
+        LineTracker {
+            input,
+            offset: 0,
+            line: 1,
+            line_start: 0,
+        }
+    }
+
+    fn locate(&mut self, offset: usize) -> (usize, usize) {
+        for (index, byte) in self.input.as_bytes()[self.offset..offset]
+            .iter()
+            .enumerate()
+        {
+            if *byte == b'\n' {
+                self.line += 1;
+                self.line_start = self.offset + index + 1;
+            }
+        }
+        self.offset = offset;
+
+        let column = self.input[self.line_start..offset].chars().count() + 1;
+        (self.line, column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tokenize, InstructionSet};
+
+    // line, column, text, enabled, value and running sum
+    type Row<'a> = (usize, usize, &'a str, bool, Option<i64>, i128);
+
+    #[test]
+    fn traces_positions_state_and_running_sum() {
+        let input = "xmul(2,4)&don't()\n_mul(5,5)é+do()mul(8,5)";
+        let tokens = tokenize(input, &InstructionSet::puzzle());
+        let entries = trace(input, &tokens, &Interpreter::PART2);
+
+        let summary: Vec<Row> = entries
+            .iter()
+            .map(|entry| {
+                (
+                    entry.line,
+                    entry.column,
+                    entry.text.as_str(),
+                    entry.enabled,
+                    entry.value,
+                    entry.sum,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, 2, "mul(2,4)", true, Some(8), 8),
+                (1, 11, "don't()", true, None, 8),
+                (2, 2, "mul(5,5)", false, Some(25), 8),
+                (2, 12, "do()", false, None, 8),
+                (2, 16, "mul(8,5)", true, Some(40), 48),
+            ]
+        );
+    }
+
+    #[test]
+    fn highlights_only_the_instructions() {
+        let input = "a mul(1,2) don't() mul(3,4)";
+        let tokens = tokenize(input, &InstructionSet::puzzle());
+        let entries = trace(input, &tokens, &Interpreter::PART2);
+
+        assert_eq!(
+            highlight(input, &entries),
+            format!("a {GREEN}mul(1,2){RESET} {GREEN}don't(){RESET} {RED}mul(3,4){RESET}")
+        );
+    }
+}
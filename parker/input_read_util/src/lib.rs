@@ -1,6 +1,8 @@
-use std::fs::File;
-use std::io::{self, BufRead,BufReader};
-use std::path::Path; 
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader};
+use std::path::Path;
+use std::str::FromStr;
 
 pub fn read_file_return_buffer<P>(filename: P) -> io::Result<Vec<String>>
 where
@@ -11,3 +13,348 @@ where
     buffered_reader.lines().collect()
 }
 
+pub fn read_input<P>(filename: P) -> io::Result<String>
+where
+    P: AsRef<Path>,
+{
+    fs::read_to_string(filename)
+}
+
+// `-` as an input path means stdin, as is usual on the command line
+pub const STDIN_PATH: &str = "-";
+
+// reads the whole input from `path`, or from stdin when there is no path or it is `-`
+pub fn read_input_or_stdin(path: Option<&str>) -> io::Result<String> {
+    match path {
+        None | Some(STDIN_PATH) => io::read_to_string(io::stdin()),
+        Some(path) => read_input(path),
+    }
+}
+
+// like `read_input_or_stdin`, but hands back a reader so large inputs can be processed line by line
+pub fn open_input_or_stdin(path: Option<&str>) -> io::Result<Box<dyn BufRead>> {
+    match path {
+        None | Some(STDIN_PATH) => Ok(Box::new(io::stdin().lock())),
+        Some(path) => Ok(Box::new(BufReader::new(File::open(path)?))),
+    }
+}
+
+// how to refer to the input in messages, e.g. "unable to read stdin"
+pub fn input_name(path: Option<&str>) -> &str {
+    match path {
+        None | Some(STDIN_PATH) => "stdin",
+        Some(path) => path,
+    }
+}
+
+// line and column are 1-based, column counts chars rather than bytes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// a run of non-blank lines, remembering where it started in the full input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Section<'a> {
+    pub text: &'a str,
+    pub first_line: usize,
+}
+
+impl Section<'_> {
+    // moves an error reported relative to this section's text to its position in the full input
+    pub fn locate(&self, error: ParseError) -> ParseError {
+        ParseError {
+            line: error.line + self.first_line - 1,
+            ..error
+        }
+    }
+}
+
+pub fn split_sections(input: &str) -> Vec<Section<'_>> {
+    let mut sections = Vec::new();
+    let mut start: Option<(usize, usize)> = None;
+    let mut offset = 0;
+
+    for (index, line) in input.split_inclusive('\n').enumerate() {
+        let is_blank = line.trim().is_empty();
+        match (start, is_blank) {
+            (None, false) => start = Some((offset, index + 1)),
+            (Some((start_offset, first_line)), true) => {
+                sections.push(Section {
+                    text: input[start_offset..offset].trim_end(),
+                    first_line,
+                });
+                start = None;
+            }
+            _ => {}
+        }
+        offset += line.len();
+    }
+
+    if let Some((start_offset, first_line)) = start {
+        sections.push(Section {
+            text: input[start_offset..].trim_end(),
+            first_line,
+        });
+    }
+
+    sections
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Separator {
+    Whitespace,
+    Char(char),
+}
+
+// parses every non-blank line into a row of values, e.g. `7 6 4 2 1` or `75,47,61`
+pub fn parse_int_rows<T: FromStr>(
+    input: &str,
+    separator: Separator,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| parse_int_row(line, index + 1, separator))
+        .collect()
+}
+
+// parses a single line into a row of values, `line_number` is used for error positions
+pub fn parse_int_row<T: FromStr>(
+    line: &str,
+    line_number: usize,
+    separator: Separator,
+) -> Result<Vec<T>, ParseError> {
+    let mut row = Vec::new();
+
+    for (column, token) in split_tokens(line, separator) {
+        let value = token.parse::<T>().map_err(|_| {
+            let found = if token.is_empty() {
+                String::from("nothing")
+            } else {
+                format!("'{}'", token)
+            };
+            ParseError::new(
+                line_number,
+                column,
+                format!("expected an integer, found {}", found),
+            )
+        })?;
+        row.push(value);
+    }
+
+    Ok(row)
+}
+
+// yields each token with its 1-based starting column
+fn split_tokens(line: &str, separator: Separator) -> Vec<(usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut token_start: Option<(usize, usize)> = None;
+
+    for (column, (byte_index, char)) in line.char_indices().enumerate() {
+        let is_separator = match separator {
+            Separator::Whitespace => char.is_whitespace(),
+            Separator::Char(separator_char) => char == separator_char,
+        };
+
+        match (token_start, is_separator) {
+            (None, false) => token_start = Some((column + 1, byte_index)),
+            (Some((start_column, start_byte)), true) => {
+                tokens.push((start_column, &line[start_byte..byte_index]));
+                token_start = None;
+            }
+            // an explicit separator with nothing before it is an empty token
+            (None, true) if separator != Separator::Whitespace => tokens.push((column + 1, "")),
+            _ => {}
+        }
+    }
+
+    match token_start {
+        Some((start_column, start_byte)) => tokens.push((start_column, &line[start_byte..])),
+        None if separator != Separator::Whitespace => tokens.push((line.chars().count() + 1, "")),
+        None => {}
+    }
+
+    tokens
+}
+
+// parses a rectangular block of characters into rows, e.g. a map of `.` and `#`
+pub fn parse_grid(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let mut grid: Vec<Vec<char>> = Vec::new();
+
+    for (index, line) in input.lines().enumerate() {
+        let row: Vec<char> = line.chars().collect();
+
+        if row.is_empty() {
+            // trailing blank lines are fine, blank lines inside the grid are not
+            if input.lines().skip(index).all(|rest| rest.is_empty()) {
+                break;
+            }
+            return Err(ParseError::new(
+                index + 1,
+                1,
+                "unexpected blank line inside grid",
+            ));
+        }
+
+        if let Some(first_row) = grid.first() {
+            if row.len() != first_row.len() {
+                return Err(ParseError::new(
+                    index + 1,
+                    row.len().min(first_row.len()) + 1,
+                    format!(
+                        "expected {} columns like the first row, found {}",
+                        first_row.len(),
+                        row.len()
+                    ),
+                ));
+            }
+        }
+
+        grid.push(row);
+    }
+
+    if grid.is_empty() {
+        return Err(ParseError::new(1, 1, "expected a grid, found empty input"));
+    }
+
+    Ok(grid)
+}
+
+// parses a single line of decimal digits such as a compact disk map, ignoring surrounding whitespace
+pub fn parse_digits(input: &str) -> Result<Vec<u8>, ParseError> {
+    let leading = &input[..input.len() - input.trim_start().len()];
+    let first_line = leading.matches('\n').count() + 1;
+    // columns count from the start of the line, including any leading whitespace on it
+    let first_column = leading
+        .rsplit('\n')
+        .next()
+        .unwrap_or_default()
+        .chars()
+        .count()
+        + 1;
+    let trimmed = input.trim();
+
+    if trimmed.is_empty() {
+        return Err(ParseError::new(
+            first_line,
+            1,
+            "expected digits, found empty input",
+        ));
+    }
+
+    let mut digits = Vec::with_capacity(trimmed.len());
+    for (column, char) in trimmed.chars().enumerate() {
+        let Some(digit) = char.to_digit(10) else {
+            return Err(ParseError::new(
+                first_line,
+                first_column + column,
+                format!("expected a digit, found {:?}", char),
+            ));
+        };
+        digits.push(digit as u8);
+    }
+
+    Ok(digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_sections_and_tracks_their_lines() {
+        let sections = split_sections("47|53\n97|13\n\n75,47\n61,13\n");
+
+        assert_eq!(
+            sections,
+            vec![
+                Section {
+                    text: "47|53\n97|13",
+                    first_line: 1
+                },
+                Section {
+                    text: "75,47\n61,13",
+                    first_line: 4
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_whitespace_and_comma_rows() {
+        let rows: Vec<Vec<i32>> = parse_int_rows("7 6  4\n\n1 2\n", Separator::Whitespace).unwrap();
+        assert_eq!(rows, vec![vec![7, 6, 4], vec![1, 2]]);
+
+        let rows: Vec<Vec<u16>> = parse_int_rows("75,47,61", Separator::Char(',')).unwrap();
+        assert_eq!(rows, vec![vec![75, 47, 61]]);
+    }
+
+    #[test]
+    fn reports_the_position_of_a_bad_integer() {
+        let error = parse_int_rows::<i32>("1 2\n3 x4", Separator::Whitespace).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(2, 3, "expected an integer, found 'x4'")
+        );
+
+        let error = parse_int_rows::<u16>("75,,61", Separator::Char(',')).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 4));
+    }
+
+    #[test]
+    fn locates_errors_inside_a_section() {
+        let input = "47|53\n\n75,47\n61,x";
+        let updates = split_sections(input)[1];
+
+        let error = parse_int_rows::<u16>(updates.text, Separator::Char(',')).unwrap_err();
+        assert_eq!(updates.locate(error).line, 4);
+    }
+
+    #[test]
+    fn parses_rectangular_grids_only() {
+        assert_eq!(
+            parse_grid("#.\n.^\n").unwrap(),
+            vec![vec!['#', '.'], vec!['.', '^']]
+        );
+
+        let error = parse_grid("#..\n.^\n").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+    }
+
+    #[test]
+    fn parses_digit_strings() {
+        assert_eq!(parse_digits("12345\n").unwrap(), vec![1, 2, 3, 4, 5]);
+
+        let error = parse_digits("12a45").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+
+        let error = parse_digits("\n  12 45").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+    }
+}